@@ -6,6 +6,7 @@ use std::result;
 
 pub mod momentum;
 pub mod trend;
+mod window;
 
 /// A specialised `Result` type for analysis operations.
 ///
@@ -13,6 +14,216 @@ pub mod trend;
 /// which may cause an error.
 pub type Result<T> = result::Result<T, AnalysisError>;
 
+/// A streaming technical indicator.
+///
+/// Indicators consume one input at a time, e.g. a value or a bar, and keep the
+/// state needed to calculate the next output internally. This allows generic
+/// code to run any indicator over a series of inputs.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Indicator, Result};
+/// use stat::analysis::trend::{Ema, Sma};
+///
+/// fn last<I: Indicator<Input = f64>>(mut indicator: I, values: &[f64])
+///     -> Result<Option<I::Output>> {
+///     let mut output = None;
+///     for value in values {
+///         output = indicator.next(*value)?;
+///     }
+///     Ok(output)
+/// }
+///
+/// let values = [3., 4., 5., 6.];
+/// assert_eq!(last(Sma::new(3).unwrap(), &values).ok(), Some(Some(5.)));
+/// assert_eq!(last(Ema::new(3).unwrap(), &values).ok(), Some(Some(5.)));
+/// ```
+pub trait Indicator {
+	/// Type of a single input.
+	type Input;
+	/// Type of a single output.
+	type Output;
+
+	/// Adds an input and returns the updated output, or `None` if the
+	/// indicator is still warming up.
+	fn next(&mut self, input: Self::Input) -> Result<Option<Self::Output>>;
+
+	/// Clears the state as if no inputs had been seen.
+	fn reset(&mut self);
+
+	/// Returns the number of inputs consumed before the first output.
+	fn lookback(&self) -> usize;
+
+	/// Returns `true` once the indicator has produced an output.
+	fn is_ready(&self) -> bool;
+}
+
+/// Runs the indicator over a series of inputs and collects the outputs.
+///
+/// The result is aligned with the input so that the warm-up positions, where
+/// the indicator has not yet produced a value, are `None`. The series must be
+/// long enough for the indicator to produce at least one value.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis;
+/// use stat::analysis::trend::Sma;
+///
+/// let values = analysis::series(Sma::new(2).unwrap(), &[3., 4., 5.]);
+/// assert_eq!(values.ok(), Some(vec![None, Some(3.5), Some(4.5)]));
+/// ```
+pub fn series<I>(mut indicator: I, inputs: &[I::Input]) -> Result<Vec<Option<I::Output>>>
+	where I: Indicator, I::Input: Copy {
+	let needed = indicator.lookback() + 1;
+	if inputs.len() < needed {
+		return Err(AnalysisError::InsufficientData { needed, got: inputs.len() });
+	}
+	inputs.iter()
+		.enumerate()
+		.map(|(i, input)| indicator.next(*input).map_err(|err| locate(err, i)))
+		.collect()
+}
+
+/// A price bar describing the values of a single period.
+///
+/// The constructor validates that the open and close prices are within the
+/// high-low range and that the volume is not negative, so indicators that
+/// consume bars do not need to validate them again.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+///
+/// let bar = Bar::new(80., 90., 50., 85., 1000., 0).unwrap();
+/// assert_eq!(bar.close(), 85.);
+/// assert!(Bar::new(80., 90., 50., 95., 1000., 0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+	open: f64,
+	high: f64,
+	low: f64,
+	close: f64,
+	volume: f64,
+	timestamp: u64,
+}
+
+impl Bar {
+	/// Creates a new bar.
+	///
+	/// # Arguments
+	///
+	/// * `open` - opening price of the period
+	/// * `high` - highest price of the period
+	/// * `low` - lowest price of the period
+	/// * `close` - closing price of the period
+	/// * `volume` - traded volume of the period
+	/// * `timestamp` - start of the period, e.g. seconds since the Unix epoch
+	pub fn new(open: f64, high: f64, low: f64, close: f64, volume: f64, timestamp: u64)
+		-> Result<Bar> {
+		check_finite(&[open, high, low, close, volume])?;
+		check_range(close, high, low)?;
+		if open > high {
+			return Err(AnalysisError::OpenGreaterThanHigh);
+		}
+		if open < low {
+			return Err(AnalysisError::OpenLessThanLow);
+		}
+		if volume < 0. {
+			return Err(AnalysisError::VolumeLessThanZero);
+		}
+		Ok(Bar {
+			open,
+			high,
+			low,
+			close,
+			volume,
+			timestamp,
+		})
+	}
+
+	/// Returns the opening price.
+	pub fn open(&self) -> f64 {
+		self.open
+	}
+
+	/// Returns the highest price.
+	pub fn high(&self) -> f64 {
+		self.high
+	}
+
+	/// Returns the lowest price.
+	pub fn low(&self) -> f64 {
+		self.low
+	}
+
+	/// Returns the closing price.
+	pub fn close(&self) -> f64 {
+		self.close
+	}
+
+	/// Returns the traded volume.
+	pub fn volume(&self) -> f64 {
+		self.volume
+	}
+
+	/// Returns the timestamp.
+	pub fn timestamp(&self) -> u64 {
+		self.timestamp
+	}
+}
+
+fn check_period(period: usize) -> Result<()> {
+	match period {
+		0 => Err(AnalysisError::InvalidPeriod(period)),
+		_ => Ok(()),
+	}
+}
+
+fn check_alpha(alpha: f64) -> Result<()> {
+	match alpha > 0. && alpha <= 1. {
+		true => Ok(()),
+		false => Err(AnalysisError::InvalidAlpha(alpha)),
+	}
+}
+
+fn check_constant(constant: f64) -> Result<()> {
+	match constant.is_finite() && constant > 0. {
+		true => Ok(()),
+		false => Err(AnalysisError::InvalidConstant(constant)),
+	}
+}
+
+fn check_finite(values: &[f64]) -> Result<()> {
+	match values.iter().position(|value| !value.is_finite()) {
+		Some(index) => Err(AnalysisError::NonFiniteInput { index }),
+		None => Ok(()),
+	}
+}
+
+fn locate(err: AnalysisError, index: usize) -> AnalysisError {
+	match err {
+		AnalysisError::NonFiniteInput { .. } => AnalysisError::NonFiniteInput { index },
+		err => err,
+	}
+}
+
+fn check_range(close: f64, high: f64, low: f64) -> Result<()> {
+	if high < low {
+		return Err(AnalysisError::HighLessThanLow);
+	}
+	if close > high {
+		return Err(AnalysisError::CloseGreaterThanHigh);
+	}
+	if close < low {
+		return Err(AnalysisError::CloseLessThanLow);
+	}
+	Ok(())
+}
+
 /// A list specifying types of analysis errors.
 #[derive(Debug)]
 pub enum AnalysisError {
@@ -26,29 +237,106 @@ pub enum AnalysisError {
 	CloseLessThanLow,
 	/// High must be greater than or equal to low.
 	HighLessThanLow,
+	/// Open must be less than or equal to high.
+	OpenGreaterThanHigh,
+	/// Open must be greater than or equal to low.
+	OpenLessThanLow,
+	/// Volume must be positive.
+	VolumeLessThanZero,
 	/// Slice must not be empty.
 	SliceIsEmpty,
+	/// Series must contain at least `needed` values.
+	InsufficientData {
+		/// Number of values needed.
+		needed: usize,
+		/// Number of values given.
+		got: usize,
+	},
+	/// Period must be greater than zero.
+	InvalidPeriod(usize),
+	/// Smoothing or acceleration factor must be greater than 0 and less than
+	/// or equal to 1.
+	InvalidAlpha(f64),
+	/// Scaling constant or multiplier must be finite and greater than 0.
+	InvalidConstant(f64),
+	/// Input value at `index` must be finite.
+	///
+	/// For slices and series the index is the position of the value, for
+	/// single values it is the position of the argument.
+	NonFiniteInput {
+		/// Position of the first non-finite value.
+		index: usize,
+	},
 }
 
 impl fmt::Display for AnalysisError {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "error: {}", self.description())
-	}
-}
-
-impl Error for AnalysisError {
-	fn description(&self) -> &str {
 		match *self {
-			AnalysisError::GainLessThanZero => "gain < 0",
-			AnalysisError::LossLessThanZero => "loss < 0",
-			AnalysisError::CloseGreaterThanHigh => "close > high",
-			AnalysisError::CloseLessThanLow => "close < low",
-			AnalysisError::HighLessThanLow => "high < low",
-			AnalysisError::SliceIsEmpty => "slice is empty",
+			AnalysisError::GainLessThanZero => write!(f, "error: gain < 0"),
+			AnalysisError::LossLessThanZero => write!(f, "error: loss < 0"),
+			AnalysisError::CloseGreaterThanHigh => write!(f, "error: close > high"),
+			AnalysisError::CloseLessThanLow => write!(f, "error: close < low"),
+			AnalysisError::HighLessThanLow => write!(f, "error: high < low"),
+			AnalysisError::OpenGreaterThanHigh => write!(f, "error: open > high"),
+			AnalysisError::OpenLessThanLow => write!(f, "error: open < low"),
+			AnalysisError::VolumeLessThanZero => write!(f, "error: volume < 0"),
+			AnalysisError::SliceIsEmpty => write!(f, "error: slice is empty"),
+			AnalysisError::InsufficientData { needed, got }
+				=> write!(f, "error: insufficient data, needed {} got {}", needed, got),
+			AnalysisError::InvalidPeriod(period)
+				=> write!(f, "error: invalid period {}", period),
+			AnalysisError::InvalidAlpha(alpha)
+				=> write!(f, "error: invalid alpha {}", alpha),
+			AnalysisError::InvalidConstant(constant)
+				=> write!(f, "error: invalid constant {}", constant),
+			AnalysisError::NonFiniteInput { index }
+				=> write!(f, "error: non-finite input at index {}", index),
 		}
 	}
+}
+
+impl Error for AnalysisError {}
 
-	fn cause(&self) -> Option<&Error> {
-		None
+#[cfg(test)]
+mod tests {
+	use analysis::{AnalysisError, Bar, Result};
+
+	#[test]
+	fn bar() {
+		let tests: [(f64, f64, f64, f64, f64, Result<()>); 15] = [
+			(80., 90., 50., 85., 1000., Ok(())),
+			(90., 90., 50., 50., 0., Ok(())),
+			(50., 90., 50., 90., 0., Ok(())),
+			(50., 50., 50., 50., 0., Ok(())),
+
+			(80., 50., 90., 85., 1000., Err(AnalysisError::HighLessThanLow)),
+			(80., 90., 50., 95., 1000., Err(AnalysisError::CloseGreaterThanHigh)),
+			(80., 90., 50., 45., 1000., Err(AnalysisError::CloseLessThanLow)),
+			(95., 90., 50., 85., 1000., Err(AnalysisError::OpenGreaterThanHigh)),
+			(45., 90., 50., 85., 1000., Err(AnalysisError::OpenLessThanLow)),
+			(80., 90., 50., 85., -1., Err(AnalysisError::VolumeLessThanZero)),
+			(80., 90., 50., 85., -0.1, Err(AnalysisError::VolumeLessThanZero)),
+			(95., 50., 90., 85., -1., Err(AnalysisError::HighLessThanLow)),
+			(f64::NAN, 90., 50., 85., 1000., Err(AnalysisError::NonFiniteInput { index: 0 })),
+			(80., f64::INFINITY, 50., 85., 1000., Err(AnalysisError::NonFiniteInput { index: 1 })),
+			(80., 90., 50., 85., f64::NAN, Err(AnalysisError::NonFiniteInput { index: 4 })),
+		];
+
+		for (i, test) in tests.iter().enumerate() {
+			let result = Bar::new(test.0, test.1, test.2, test.3, test.4, i as u64);
+			match (result, test.5.as_ref()) {
+				(Ok(bar), Ok(_)) => {
+					assert_eq!(bar.open(), test.0);
+					assert_eq!(bar.high(), test.1);
+					assert_eq!(bar.low(), test.2);
+					assert_eq!(bar.close(), test.3);
+					assert_eq!(bar.volume(), test.4);
+					assert_eq!(bar.timestamp(), i as u64);
+				},
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
 	}
 }
@@ -1,7 +1,9 @@
 //! Momentum contains simple technical analysis indicators showing the
 //! difference between period's closing price and the closing price N
 //! periods ago.
-use analysis::{AnalysisError, Result};
+use analysis::{self, AnalysisError, Bar, Indicator, Result};
+use analysis::trend::{self, Ema, Macd, Sma, Wma};
+use analysis::window::{Extremum, MeanDeviation, PercentRank, Ring, Sum};
 
 /// Relative strength index (RSI) is a momentum oscillator developed by J.
 /// Welles Wilder Jr. that measures the speed and change of movements. The
@@ -24,6 +26,7 @@ use analysis::{AnalysisError, Result};
 /// assert_eq!(value.ok(), Some(75.));
 /// ```
 pub fn relative_strength_index(gain: f64, loss: f64) -> Result<f64> {
+	analysis::check_finite(&[gain, loss])?;
 	if gain < 0. {
 		return Err(AnalysisError::GainLessThanZero);
 	}
@@ -36,16 +39,152 @@ pub fn relative_strength_index(gain: f64, loss: f64) -> Result<f64> {
 	})
 }
 
+/// Streaming relative strength index calculated from closing prices.
+///
+/// `Rsi` splits the change between consecutive closing prices into gains and
+/// losses and smooths them using Wilder's method. The first averages are the
+/// simple averages of the first `period` changes, after which each average is
+/// updated as `(previous * (period - 1) + current) / period`. The averages are
+/// then passed to `relative_strength_index`.
+///
+/// The first value is available after `period + 1` closing prices. `Rsi`
+/// defaults to the 14 periods suggested by Wilder.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Rsi;
+///
+/// let mut rsi = Rsi::new(2).unwrap();
+/// assert_eq!(rsi.feed(&[10., 11., 10.]).ok(), Some(Some(50.)));
+/// assert_eq!(rsi.next(13.).ok(), Some(Some(87.5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rsi {
+	period: usize,
+	previous: Option<f64>,
+	count: usize,
+	gain: f64,
+	loss: f64,
+	value: Option<f64>,
+}
+
+impl Rsi {
+	/// Creates a new relative strength index over `period` price changes.
+	pub fn new(period: usize) -> Result<Rsi> {
+		analysis::check_period(period)?;
+		Ok(Rsi {
+			period,
+			previous: None,
+			count: 0,
+			gain: 0.,
+			loss: 0.,
+			value: None,
+		})
+	}
+
+	/// Adds all closing prices in the slice and returns the index after the
+	/// last one, or `None` if still warming up.
+	pub fn feed(&mut self, closes: &[f64]) -> Result<Option<f64>> {
+		for (i, close) in closes.iter().enumerate() {
+			self.next(*close).map_err(|err| analysis::locate(err, i))?;
+		}
+		Ok(self.value)
+	}
+
+	/// Returns the current index, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Rsi {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, close: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[close])?;
+		let change = match self.previous.replace(close) {
+			Some(previous) => close - previous,
+			None => return Ok(None),
+		};
+		let gain = change.max(0.);
+		let loss = (-change).max(0.);
+		let period = self.period as f64;
+		if self.count < self.period {
+			self.count += 1;
+			self.gain += gain;
+			self.loss += loss;
+			if self.count < self.period {
+				return Ok(None);
+			}
+			self.gain /= period;
+			self.loss /= period;
+		} else {
+			self.gain = (self.gain * (period - 1.) + gain) / period;
+			self.loss = (self.loss * (period - 1.) + loss) / period;
+		}
+		self.value = Some(relative_strength_index(self.gain, self.loss)?);
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.previous = None;
+		self.count = 0;
+		self.gain = 0.;
+		self.loss = 0.;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.period
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for Rsi {
+	fn default() -> Rsi {
+		Rsi::new(14).unwrap()
+	}
+}
+
+/// Calculates the relative strength index for every closing price of the
+/// series.
+///
+/// The result is aligned with the input so that the first `period` positions,
+/// before the first averages are available, are `None`.
+///
+/// # Arguments
+///
+/// * `closes` - array of closing prices
+/// * `period` - number of price changes
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::rsi_series(&[10., 11., 10., 13.], 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(50.), Some(87.5)]));
+/// ```
+pub fn rsi_series(closes: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Rsi::new(period)?, closes)
+}
+
 /// Stochastic oscillator is a momentum indicator developed by Dr. George Lane
 /// that shows the location of the value relative to the high-low range. The
 /// output of the function oscillates between 0 and 100.
 ///
 /// Typical number of periods for stochastic oscillator calculations is 5, 9,
 /// or 14. Stochastic oscillator is used together with 1 or 2 signal lines.
-/// The 1st signal line is the exponential moving averege of the stochastic
-/// oscillator value and the 2nd signal line is the exponential moving average
-/// of the 1st signal line value. Signal lines are typically calculated from
-/// 3 previous periods.
+/// The 1st signal line is the moving averege of the stochastic oscillator
+/// value and the 2nd signal line is the moving average of the 1st signal line
+/// value. Signal lines are typically calculated from 3 previous periods and
+/// are available from `Stochastic`.
 ///
 /// `high` and `low` arguments are the highest high and the lowest low
 /// respectively for the used period.
@@ -68,19 +207,425 @@ pub fn relative_strength_index(gain: f64, loss: f64) -> Result<f64> {
 /// assert_eq!(value.ok(), Some(75.));
 /// ```
 pub fn stochastic_oscillator(close: f64, high: f64, low: f64) -> Result<f64> {
-	if high < low {
-		return Err(AnalysisError::HighLessThanLow);
+	analysis::check_finite(&[close, high, low])?;
+	analysis::check_range(close, high, low)?;
+	Ok(stochastic(close, high, low))
+}
+
+/// Unchecked `stochastic_oscillator` used by the streaming indicators, whose
+/// inputs are already known to be valid.
+fn stochastic(close: f64, high: f64, low: f64) -> f64 {
+	match high == low {
+		true => 50.,
+		false => 100. * (close - low) / (high - low),
 	}
-	if close > high {
-		return Err(AnalysisError::CloseGreaterThanHigh);
+}
+
+/// Streaming stochastic oscillator calculated from bar data.
+///
+/// `Stochastic` keeps track of the highest high and the lowest low of the last
+/// `period` bars and applies the `stochastic_oscillator` formula to them and
+/// the closing price. The bars were validated when created, so they are not
+/// checked again. The raw value is smoothed with a simple moving average of
+/// `k_smoothing` bars to get %K, and %K is smoothed with a simple moving
+/// average of `d_smoothing` bars to get the %D signal line.
+///
+/// The fast stochastic uses the raw value as %K, the slow stochastic uses the
+/// same smoothing for %K and %D, and the full stochastic allows both to be
+/// chosen freely.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::Stochastic;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut stochastic = Stochastic::fast(2, 2).unwrap();
+/// assert_eq!(stochastic.next(bar(8., 10., 6.)).ok(), Some(None));
+/// assert_eq!(stochastic.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(stochastic.next(bar(12., 12., 9.)).ok(), Some(Some((100., 87.5))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stochastic {
+	high: Extremum,
+	low: Extremum,
+	k: Sma,
+	d: Sma,
+	value: Option<(f64, f64)>,
+}
+
+impl Stochastic {
+	/// Creates a new full stochastic oscillator.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of bars for the highest high and the lowest low
+	/// * `k_smoothing` - number of periods for %K smoothing
+	/// * `d_smoothing` - number of periods for %D smoothing
+	pub fn new(period: usize, k_smoothing: usize, d_smoothing: usize) -> Result<Stochastic> {
+		analysis::check_period(period)?;
+		Ok(Stochastic {
+			high: Extremum::max(period),
+			low: Extremum::min(period),
+			k: Sma::new(k_smoothing)?,
+			d: Sma::new(d_smoothing)?,
+			value: None,
+		})
 	}
-	if close < low {
-		return Err(AnalysisError::CloseLessThanLow);
+
+	/// Creates a new fast stochastic oscillator where %K is not smoothed.
+	pub fn fast(period: usize, d_smoothing: usize) -> Result<Stochastic> {
+		Stochastic::new(period, 1, d_smoothing)
 	}
-	Ok(match high == low {
-		true => 50.,
-		false => 100. * (close - low) / (high - low),
-	})
+
+	/// Creates a new slow stochastic oscillator where %K and %D are smoothed
+	/// over the same number of periods.
+	pub fn slow(period: usize, smoothing: usize) -> Result<Stochastic> {
+		Stochastic::new(period, smoothing, smoothing)
+	}
+
+	/// Returns the current `(k, d)` pair, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, f64)> {
+		self.value
+	}
+}
+
+impl Indicator for Stochastic {
+	type Input = Bar;
+	type Output = (f64, f64);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, f64)>> {
+		let close = bar.close();
+		let extremes = (self.high.next(bar.high()), self.low.next(bar.low()));
+		if let (Some(high), Some(low)) = extremes {
+			let value = stochastic(close, high, low);
+			if let Some(k) = self.k.next(value)? {
+				if let Some(d) = self.d.next(k)? {
+					self.value = Some((k, d));
+				}
+			}
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.high.reset();
+		self.low.reset();
+		self.k.reset();
+		self.d.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.high.lookback() + self.k.lookback() + self.d.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the full stochastic oscillator for every bar of the series.
+///
+/// The result is aligned with the input so that the warm-up positions, before
+/// the first `(k, d)` pair is available, are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars for the highest high and the lowest low
+/// * `k_smoothing` - number of periods for %K smoothing
+/// * `d_smoothing` - number of periods for %D smoothing
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let bars = [bar(8., 10., 6.), bar(9., 10., 8.), bar(12., 12., 9.)];
+/// let values = momentum::stochastic_series(&bars, 2, 1, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some((100., 87.5))]));
+/// ```
+pub fn stochastic_series(bars: &[Bar], period: usize, k_smoothing: usize,
+	d_smoothing: usize) -> Result<Vec<Option<(f64, f64)>>> {
+	analysis::series(Stochastic::new(period, k_smoothing, d_smoothing)?, bars)
+}
+
+/// Streaming stochastic RSI developed by Tushar Chande and Stanley Kroll.
+///
+/// `StochRsi` applies the `stochastic_oscillator` formula to the relative
+/// strength index instead of prices: the RSI of the closing prices is placed
+/// within the range of its own highest and lowest values over the last
+/// `period` RSI values. Like `Stochastic`, the raw value is smoothed with a
+/// simple moving average of `k_smoothing` values to get %K, and %K with a
+/// simple moving average of `d_smoothing` values to get %D. The output
+/// oscillates between 0 and 100, and 50 is used while the RSI is flat.
+///
+/// `StochRsi` defaults to the 14 periods for the RSI and its range and 3
+/// periods for both smoothings.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::StochRsi;
+///
+/// let mut stoch_rsi = StochRsi::new(1, 2, 1, 1).unwrap();
+/// assert_eq!(stoch_rsi.next(10.).ok(), Some(None));
+/// assert_eq!(stoch_rsi.next(11.).ok(), Some(None));
+/// assert_eq!(stoch_rsi.next(10.).ok(), Some(Some((0., 0.))));
+/// assert_eq!(stoch_rsi.next(12.).ok(), Some(Some((100., 100.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StochRsi {
+	rsi: Rsi,
+	high: Extremum,
+	low: Extremum,
+	k: Sma,
+	d: Sma,
+	value: Option<(f64, f64)>,
+}
+
+impl StochRsi {
+	/// Creates a new stochastic RSI.
+	///
+	/// # Arguments
+	///
+	/// * `rsi_period` - number of price changes of the RSI
+	/// * `period` - number of RSI values for the highest and the lowest RSI
+	/// * `k_smoothing` - number of periods for %K smoothing
+	/// * `d_smoothing` - number of periods for %D smoothing
+	pub fn new(rsi_period: usize, period: usize, k_smoothing: usize, d_smoothing: usize)
+		-> Result<StochRsi> {
+		analysis::check_period(period)?;
+		Ok(StochRsi {
+			rsi: Rsi::new(rsi_period)?,
+			high: Extremum::max(period),
+			low: Extremum::min(period),
+			k: Sma::new(k_smoothing)?,
+			d: Sma::new(d_smoothing)?,
+			value: None,
+		})
+	}
+
+	/// Returns the current `(k, d)` pair, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, f64)> {
+		self.value
+	}
+}
+
+impl Indicator for StochRsi {
+	type Input = f64;
+	type Output = (f64, f64);
+
+	fn next(&mut self, close: f64) -> Result<Option<(f64, f64)>> {
+		let rsi = match self.rsi.next(close)? {
+			Some(rsi) => rsi,
+			None => return Ok(None),
+		};
+		if let (Some(high), Some(low)) = (self.high.next(rsi), self.low.next(rsi)) {
+			let value = stochastic(rsi, high, low);
+			if let Some(k) = self.k.next(value)? {
+				if let Some(d) = self.d.next(k)? {
+					self.value = Some((k, d));
+				}
+			}
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.rsi.reset();
+		self.high.reset();
+		self.low.reset();
+		self.k.reset();
+		self.d.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.rsi.lookback() + self.high.lookback() + self.k.lookback() + self.d.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for StochRsi {
+	fn default() -> StochRsi {
+		StochRsi::new(14, 14, 3, 3).unwrap()
+	}
+}
+
+/// Calculates the stochastic RSI for every closing price of the series.
+///
+/// The result is aligned with the input so that the warm-up positions, before
+/// the first `(k, d)` pair is available, are `None`.
+///
+/// # Arguments
+///
+/// * `closes` - array of closing prices
+/// * `rsi_period` - number of price changes of the RSI
+/// * `period` - number of RSI values for the highest and the lowest RSI
+/// * `k_smoothing` - number of periods for %K smoothing
+/// * `d_smoothing` - number of periods for %D smoothing
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::stoch_rsi_series(&[10., 11., 10., 12.], 1, 2, 1, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, None, Some((100., 50.))]));
+/// ```
+pub fn stoch_rsi_series(closes: &[f64], rsi_period: usize, period: usize,
+	k_smoothing: usize, d_smoothing: usize) -> Result<Vec<Option<(f64, f64)>>> {
+	analysis::series(StochRsi::new(rsi_period, period, k_smoothing, d_smoothing)?, closes)
+}
+
+/// Streaming Connors RSI developed by Larry Connors.
+///
+/// Connors RSI is the average of three components that all oscillate between
+/// 0 and 100:
+///
+/// * the RSI of the closing prices over `rsi_period` changes,
+/// * the RSI of the streak over `streak_period` changes, where the streak is
+///   the number of consecutive up closes, or the negated number of
+///   consecutive down closes, and is 0 when the close did not change,
+/// * the percent rank of the one-period rate of change, i.e. the percentage
+///   of the previous `rank_period` rates of change that are less than the
+///   current one.
+///
+/// The streak of the first closing price is 0. The first value is available
+/// once all components are, which for the default 3, 2 and 100 periods
+/// suggested by Connors is after 102 closing prices.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::ConnorsRsi;
+///
+/// let mut connors_rsi = ConnorsRsi::new(1, 1, 1).unwrap();
+/// assert_eq!(connors_rsi.next(10.).ok(), Some(None));
+/// assert_eq!(connors_rsi.next(11.).ok(), Some(None));
+/// assert_eq!(connors_rsi.next(13.).ok(), Some(Some(100.)));
+/// assert_eq!(connors_rsi.next(12.).ok(), Some(Some(0.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConnorsRsi {
+	rsi: Rsi,
+	streak_rsi: Rsi,
+	rank: PercentRank,
+	previous: Option<f64>,
+	streak: f64,
+	value: Option<f64>,
+}
+
+impl ConnorsRsi {
+	/// Creates a new Connors RSI.
+	///
+	/// # Arguments
+	///
+	/// * `rsi_period` - number of price changes of the price RSI
+	/// * `streak_period` - number of streak changes of the streak RSI
+	/// * `rank_period` - number of previous rates of change for the percent rank
+	pub fn new(rsi_period: usize, streak_period: usize, rank_period: usize)
+		-> Result<ConnorsRsi> {
+		analysis::check_period(rank_period)?;
+		Ok(ConnorsRsi {
+			rsi: Rsi::new(rsi_period)?,
+			streak_rsi: Rsi::new(streak_period)?,
+			rank: PercentRank::new(rank_period),
+			previous: None,
+			streak: 0.,
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for ConnorsRsi {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, close: f64) -> Result<Option<f64>> {
+		let rsi = self.rsi.next(close)?;
+		let rank = match self.previous.replace(close) {
+			Some(previous) => {
+				self.streak = match close {
+					close if close > previous => self.streak.max(0.) + 1.,
+					close if close < previous => self.streak.min(0.) - 1.,
+					_ => 0.,
+				};
+				self.rank.next(rate_of_change(close, previous)?)
+			},
+			None => None,
+		};
+		let streak_rsi = self.streak_rsi.next(self.streak)?;
+		if let (Some(rsi), Some(streak_rsi), Some(rank)) = (rsi, streak_rsi, rank) {
+			self.value = Some((rsi + streak_rsi + rank) / 3.);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.rsi.reset();
+		self.streak_rsi.reset();
+		self.rank.reset();
+		self.previous = None;
+		self.streak = 0.;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.rsi.lookback()
+			.max(self.streak_rsi.lookback())
+			.max(self.rank.lookback() + 1)
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for ConnorsRsi {
+	fn default() -> ConnorsRsi {
+		ConnorsRsi::new(3, 2, 100).unwrap()
+	}
+}
+
+/// Calculates the Connors RSI for every closing price of the series.
+///
+/// The result is aligned with the input so that the warm-up positions, before
+/// all three components are available, are `None`.
+///
+/// # Arguments
+///
+/// * `closes` - array of closing prices
+/// * `rsi_period` - number of price changes of the price RSI
+/// * `streak_period` - number of streak changes of the streak RSI
+/// * `rank_period` - number of previous rates of change for the percent rank
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::connors_rsi_series(&[10., 11., 13., 12.], 1, 1, 1);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(100.), Some(0.)]));
+/// ```
+pub fn connors_rsi_series(closes: &[f64], rsi_period: usize, streak_period: usize,
+	rank_period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(ConnorsRsi::new(rsi_period, streak_period, rank_period)?, closes)
 }
 
 /// Williams %R is a momentum indicator developed by Larry R. Williams that
@@ -112,147 +657,3233 @@ pub fn stochastic_oscillator(close: f64, high: f64, low: f64) -> Result<f64> {
 /// assert_eq!(value.ok(), Some(-25.));
 /// ```
 pub fn williams_percent_r(close: f64, high: f64, low: f64) -> Result<f64> {
-	if high < low {
-		return Err(AnalysisError::HighLessThanLow);
-	}
-	if close > high {
-		return Err(AnalysisError::CloseGreaterThanHigh);
-	}
-	if close < low {
-		return Err(AnalysisError::CloseLessThanLow);
-	}
-	Ok(match high == low {
+	analysis::check_finite(&[close, high, low])?;
+	analysis::check_range(close, high, low)?;
+	Ok(percent_r(close, high, low))
+}
+
+/// Unchecked `williams_percent_r` used by `WilliamsR`.
+fn percent_r(close: f64, high: f64, low: f64) -> f64 {
+	match high == low {
 		true => -50.,
 		false => -100. * (high - close) / (high - low),
-	})
+	}
 }
 
-#[cfg(test)]
-mod tests {
-	extern crate math;
-	use analysis::{AnalysisError, Result};
-	use self::math::round::half_up;
-	use std::error::Error;
+/// Streaming Williams %R calculated from bar data.
+///
+/// `WilliamsR` keeps track of the highest high and the lowest low of the last
+/// `period` bars and applies the `williams_percent_r` formula to them and the
+/// closing price without validating the bars again, so -50 is returned when
+/// the range is flat.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::WilliamsR;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut williams_r = WilliamsR::new(2).unwrap();
+/// assert_eq!(williams_r.next(bar(8., 10., 6.)).ok(), Some(None));
+/// assert_eq!(williams_r.next(bar(9., 10., 8.)).ok(), Some(Some(-25.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WilliamsR {
+	high: Extremum,
+	low: Extremum,
+	value: Option<f64>,
+}
 
-	#[test]
-	fn relative_strength_index() {
-		let tests: [(f64, f64, Result<f64>); 24] = [
-			(0.238386, 0.099593, Ok(70.532785)),
-			(0.221358, 0.112422, Ok(66.318533)),
-			(0.207690, 0.104392, Ok(66.549817)),
-			(0.219912, 0.096935, Ok(69.406370)),
-			(0.204204, 0.103540, Ok(66.355152)),
-			(0.189618, 0.137451, Ok(57.974923)),
-			(0.216667, 0.127633, Ok(62.929712)),
-			(0.204040, 0.118517, Ok(63.257037)),
-			(0.189466, 0.148508, Ok(56.059342)),
-			(0.228633, 0.137901, Ok(62.377024)),
-			(0.212302, 0.175765, Ok(54.707563)),
-			(0.197137, 0.193832, Ok(50.422668)),
-			(0.183056, 0.274701, Ok(39.989776)),
-			(0.180659, 0.255079, Ok(41.460465)),
-			(0.170598, 0.236859, Ok(41.868958)),
-			(0.183348, 0.219941, Ok(45.463179)),
-			(0.170252, 0.286138, Ok(37.304060)),
-			(0.158091, 0.319821, Ok(33.079521)),
-			(0.180270, 0.296977, Ok(37.772893)),
+impl WilliamsR {
+	/// Creates a new Williams %R over `period` bars.
+	pub fn new(period: usize) -> Result<WilliamsR> {
+		analysis::check_period(period)?;
+		Ok(WilliamsR {
+			high: Extremum::max(period),
+			low: Extremum::min(period),
+			value: None,
+		})
+	}
 
-			(1., 0., Ok(100.)),
-			(0., 1., Ok(0.)),
-			(1., 1., Ok(50.)),
-			(-1., 0., Err(AnalysisError::GainLessThanZero)),
-			(0., -1., Err(AnalysisError::LossLessThanZero)),
-		];
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
 
-		for test in &tests {
-			let result = super::relative_strength_index(test.0, test.1);
-			match (result, test.2.as_ref()) {
-				(Ok(val), Ok(exp))
-					=> assert_eq!(half_up(val, 6), *exp),
-				(Err(err), Err(exp))
-					=> assert_eq!(err.description(), exp.description()),
-				_ => panic!("return type mismatch"),
-			}
+impl Indicator for WilliamsR {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		let close = bar.close();
+		let extremes = (self.high.next(bar.high()), self.low.next(bar.low()));
+		if let (Some(high), Some(low)) = extremes {
+			self.value = Some(percent_r(close, high, low));
 		}
+		Ok(self.value)
 	}
 
-	#[test]
-	fn stochastic_oscillator() {
-		let tests: [(f64, f64, f64, Result<f64>); 23] = [
-			(127.2876, 128.4317, 124.5615, Ok(70.438220)),
-			(127.1781, 128.4317, 124.5615, Ok(67.608909)),
-			(128.0138, 128.4317, 124.5615, Ok(89.202108)),
-			(127.1085, 128.4317, 124.5615, Ok(65.810552)),
-			(127.7253, 128.4317, 124.5615, Ok(81.747713)),
-			(127.0587, 128.4317, 124.5615, Ok(64.523797)),
-			(127.3273, 128.2725, 124.5615, Ok(74.529776)),
-			(128.7103, 128.7700, 124.5615, Ok(98.581442)),
-			(127.8745, 129.2873, 124.5615, Ok(70.104533)),
-			(128.5809, 130.0633, 124.5615, Ok(73.056091)),
-			(128.6008, 130.0633, 124.5615, Ok(73.417791)),
-			(127.9342, 130.0633, 124.5715, Ok(61.231290)),
-			(128.1133, 130.0633, 125.0689, Ok(60.956271)),
-			(127.5960, 130.0633, 125.9245, Ok(40.386102)),
-			(127.5960, 130.0633, 125.9245, Ok(40.386102)),
+	fn reset(&mut self) {
+		self.high.reset();
+		self.low.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.high.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates Williams %R for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions, where the window is not yet full, are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars for the highest high and the lowest low
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let bars = [bar(8., 10., 6.), bar(9., 10., 8.), bar(11., 12., 9.)];
+/// let values = momentum::williams_r_series(&bars, 2);
+/// assert_eq!(values.ok(), Some(vec![None, Some(-25.), Some(-25.)]));
+/// ```
+pub fn williams_r_series(bars: &[Bar], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(WilliamsR::new(period)?, bars)
+}
+
+/// Rate of change (ROC) is a momentum oscillator that measures the percentage
+/// change between the current value and the value N periods ago. Positive
+/// values show upward momentum and negative values downward momentum.
+///
+/// The formula is modified to return 0 if the previous value is 0.
+///
+/// # Arguments
+///
+/// * `value` - current value
+/// * `previous` - value N periods ago
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let value = momentum::rate_of_change(55., 50.);
+/// assert_eq!(value.ok(), Some(10.));
+/// ```
+pub fn rate_of_change(value: f64, previous: f64) -> Result<f64> {
+	analysis::check_finite(&[value, previous])?;
+	Ok(match previous {
+		0. => 0.,
+		_ => 100. * (value - previous) / previous,
+	})
+}
+
+/// Rate of change ratio (ROCR) expresses the change between the current value
+/// and the value N periods ago as a ratio, so it oscillates around 1 instead
+/// of around 0.
+///
+/// The formula is modified to return 1 if the previous value is 0.
+///
+/// # Arguments
+///
+/// * `value` - current value
+/// * `previous` - value N periods ago
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let value = momentum::rate_of_change_ratio(55., 50.);
+/// assert_eq!(value.ok(), Some(1.1));
+/// ```
+pub fn rate_of_change_ratio(value: f64, previous: f64) -> Result<f64> {
+	analysis::check_finite(&[value, previous])?;
+	Ok(match previous {
+		0. => 1.,
+		_ => value / previous,
+	})
+}
+
+/// Chande momentum oscillator (CMO) developed by Tushar Chande compares the
+/// sum of gains with the sum of losses over the period. Unlike RSI the sums
+/// are not smoothed, and the output oscillates between -100 and 100. Values
+/// above 50 are considered overbought and values below -50 oversold.
+///
+/// The formula is modified to return 0 if both sums are 0.
+///
+/// # Arguments
+///
+/// * `gains` - sum of gains for the period
+/// * `losses` - sum of losses for the period
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let value = momentum::chande_momentum_oscillator(5.5, 2.5);
+/// assert_eq!(value.ok(), Some(37.5));
+/// ```
+pub fn chande_momentum_oscillator(gains: f64, losses: f64) -> Result<f64> {
+	analysis::check_finite(&[gains, losses])?;
+	if gains < 0. {
+		return Err(AnalysisError::GainLessThanZero);
+	}
+	if losses < 0. {
+		return Err(AnalysisError::LossLessThanZero);
+	}
+	Ok(match gains + losses {
+		0. => 0.,
+		total => 100. * (gains - losses) / total,
+	})
+}
+
+/// Streaming momentum calculated as the difference between the current value
+/// and the value `period` values ago.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Momentum;
+///
+/// let mut momentum = Momentum::new(2).unwrap();
+/// assert_eq!(momentum.next(10.).ok(), Some(None));
+/// assert_eq!(momentum.next(12.).ok(), Some(None));
+/// assert_eq!(momentum.next(13.).ok(), Some(Some(3.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Momentum {
+	window: Ring<f64>,
+	value: Option<f64>,
+}
+
+impl Momentum {
+	/// Creates a new momentum over `period` values.
+	pub fn new(period: usize) -> Result<Momentum> {
+		analysis::check_period(period)?;
+		Ok(Momentum {
+			window: Ring::new(period),
+			value: None,
+		})
+	}
+
+	/// Returns the current momentum, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Momentum {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		if let Some(previous) = self.window.push(value) {
+			self.value = Some(value - previous);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the momentum for every value of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods between the compared values
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::momentum_series(&[10., 12., 13.], 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some(2.), Some(1.)]));
+/// ```
+pub fn momentum_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Momentum::new(period)?, values)
+}
+
+/// Streaming rate of change passing the current value and the value `period`
+/// values ago to `rate_of_change`.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Roc;
+///
+/// let mut roc = Roc::new(1).unwrap();
+/// assert_eq!(roc.next(50.).ok(), Some(None));
+/// assert_eq!(roc.next(55.).ok(), Some(Some(10.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Roc {
+	window: Ring<f64>,
+	value: Option<f64>,
+}
+
+impl Roc {
+	/// Creates a new rate of change over `period` values.
+	pub fn new(period: usize) -> Result<Roc> {
+		analysis::check_period(period)?;
+		Ok(Roc {
+			window: Ring::new(period),
+			value: None,
+		})
+	}
+
+	/// Returns the current rate of change, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Roc {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		if let Some(previous) = self.window.push(value) {
+			self.value = Some(rate_of_change(value, previous)?);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the rate of change for every value of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods between the compared values
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::roc_series(&[50., 55., 0., 10.], 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some(10.), Some(-100.), Some(0.)]));
+/// ```
+pub fn roc_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Roc::new(period)?, values)
+}
+
+/// Streaming rate of change ratio passing the current value and the value
+/// `period` values ago to `rate_of_change_ratio`.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Rocr;
+///
+/// let mut rocr = Rocr::new(1).unwrap();
+/// assert_eq!(rocr.next(50.).ok(), Some(None));
+/// assert_eq!(rocr.next(55.).ok(), Some(Some(1.1)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rocr {
+	window: Ring<f64>,
+	value: Option<f64>,
+}
+
+impl Rocr {
+	/// Creates a new rate of change ratio over `period` values.
+	pub fn new(period: usize) -> Result<Rocr> {
+		analysis::check_period(period)?;
+		Ok(Rocr {
+			window: Ring::new(period),
+			value: None,
+		})
+	}
+
+	/// Returns the current ratio, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Rocr {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		if let Some(previous) = self.window.push(value) {
+			self.value = Some(rate_of_change_ratio(value, previous)?);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the rate of change ratio for every value of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods between the compared values
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::rocr_series(&[50., 55., 0., 10.], 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some(1.1), Some(0.), Some(1.)]));
+/// ```
+pub fn rocr_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Rocr::new(period)?, values)
+}
+
+/// Streaming Chande momentum oscillator calculated from closing prices.
+///
+/// `Cmo` keeps the gains and losses of the last `period` price changes and
+/// passes their sums to `chande_momentum_oscillator`. The first value is
+/// available after `period + 1` closing prices.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Cmo;
+///
+/// let mut cmo = Cmo::new(2).unwrap();
+/// assert_eq!(cmo.next(10.).ok(), Some(None));
+/// assert_eq!(cmo.next(13.).ok(), Some(None));
+/// assert_eq!(cmo.next(12.).ok(), Some(Some(50.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cmo {
+	changes: Ring<(f64, f64)>,
+	gains: Sum,
+	losses: Sum,
+	previous: Option<f64>,
+	value: Option<f64>,
+}
+
+impl Cmo {
+	/// Creates a new Chande momentum oscillator over `period` price changes.
+	pub fn new(period: usize) -> Result<Cmo> {
+		analysis::check_period(period)?;
+		Ok(Cmo {
+			changes: Ring::new(period),
+			gains: Sum::default(),
+			losses: Sum::default(),
+			previous: None,
+			value: None,
+		})
+	}
+
+	/// Returns the current oscillator value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Cmo {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, close: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[close])?;
+		let change = match self.previous.replace(close) {
+			Some(previous) => close - previous,
+			None => return Ok(None),
+		};
+		let change = (change.max(0.), (-change).max(0.));
+		if let Some((gain, loss)) = self.changes.push(change) {
+			self.gains.add(-gain);
+			self.losses.add(-loss);
+		}
+		self.gains.add(change.0);
+		self.losses.add(change.1);
+		if self.changes.is_full() {
+			// Kahan summation may leave a tiny negative residue after a loss
+			// leaves the window, which must not be mistaken for a bad input.
+			let sums = (self.gains.value().max(0.), self.losses.value().max(0.));
+			self.value = Some(chande_momentum_oscillator(sums.0, sums.1)?);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.changes.clear();
+		self.gains.reset();
+		self.losses.reset();
+		self.previous = None;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.changes.capacity()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the Chande momentum oscillator for every closing price of the
+/// series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `closes` - array of closing prices
+/// * `period` - number of price changes
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::cmo_series(&[10., 13., 12., 12.], 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(50.), Some(-100.)]));
+/// ```
+pub fn cmo_series(closes: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Cmo::new(period)?, closes)
+}
+
+/// Commodity channel index (CCI) developed by Donald Lambert measures the
+/// deviation of the typical price `(high + low + close) / 3` from its simple
+/// moving average, scaled by the mean absolute deviation of the typical
+/// prices. Lambert chose the constant 0.015 so that roughly 70 to 80 percent
+/// of the values fall between -100 and 100, and suggested 20 periods.
+///
+/// The last typical price of the slice is the current one. The formula is
+/// modified to return 0 if the typical prices do not deviate from their
+/// average.
+///
+/// # Arguments
+///
+/// * `typical_prices` - array of typical prices for the period
+/// * `constant` - scaling constant greater than 0, typically 0.015
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let value = momentum::commodity_channel_index(&[1., 2., 3., 4.], 0.015);
+/// assert_eq!(value.ok(), Some(100.));
+/// ```
+pub fn commodity_channel_index(typical_prices: &[f64], constant: f64) -> Result<f64> {
+	let average = trend::simple_moving_average(typical_prices)?;
+	analysis::check_constant(constant)?;
+	let deviation = typical_prices.iter()
+		.fold(0., |sum, price| sum + (price - average).abs());
+	let deviation = deviation / typical_prices.len() as f64;
+	let price = typical_prices[typical_prices.len() - 1];
+	Ok(channel_index(price, average, deviation, constant))
+}
+
+fn channel_index(price: f64, average: f64, deviation: f64, constant: f64) -> f64 {
+	match constant * deviation {
+		0. => 0.,
+		scale => (price - average) / scale,
+	}
+}
+
+/// Streaming commodity channel index calculated from bar data.
+///
+/// `Cci` keeps the typical prices of the last `period` bars and measures the
+/// mean absolute deviation from their current average on every update, so
+/// each update visits the whole window. `Cci` defaults to Lambert's 20
+/// periods and the constant 0.015.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::Cci;
+///
+/// let bar = |price| Bar::new(price, price, price, price, 0., 0).unwrap();
+/// let mut cci = Cci::new(2, 0.015).unwrap();
+/// assert_eq!(cci.next(bar(1.)).ok(), Some(None));
+/// assert_eq!(cci.next(bar(2.)).ok(), Some(Some(66.66666666666667)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cci {
+	constant: f64,
+	deviation: MeanDeviation,
+	value: Option<f64>,
+}
+
+impl Cci {
+	/// Creates a new commodity channel index.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of bars
+	/// * `constant` - scaling constant greater than 0, typically 0.015
+	pub fn new(period: usize, constant: f64) -> Result<Cci> {
+		analysis::check_period(period)?;
+		analysis::check_constant(constant)?;
+		Ok(Cci {
+			constant,
+			deviation: MeanDeviation::new(period),
+			value: None,
+		})
+	}
+
+	/// Returns the current index, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Cci {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		let price = (bar.high() + bar.low() + bar.close()) / 3.;
+		if let Some((average, deviation)) = self.deviation.next(price) {
+			self.value = Some(channel_index(price, average, deviation, self.constant));
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.deviation.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.deviation.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for Cci {
+	fn default() -> Cci {
+		Cci::new(20, 0.015).unwrap()
+	}
+}
+
+/// Calculates the commodity channel index for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions, where the window is not yet full, are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars
+/// * `constant` - scaling constant greater than 0, typically 0.015
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |price| Bar::new(price, price, price, price, 0., 0).unwrap();
+/// let bars = [bar(1.), bar(2.), bar(3.), bar(4.)];
+/// let values = momentum::cci_series(&bars, 4, 0.015);
+/// assert_eq!(values.ok(), Some(vec![None, None, None, Some(100.)]));
+/// ```
+pub fn cci_series(bars: &[Bar], period: usize, constant: f64) -> Result<Vec<Option<f64>>> {
+	analysis::series(Cci::new(period, constant)?, bars)
+}
+
+/// Detrended price oscillator (DPO) removes the trend from the values by
+/// subtracting a simple moving average displaced `period / 2 + 1` values back,
+/// which makes cycles shorter than the period easier to spot.
+///
+/// The last value of the slice is the current one and the average is taken
+/// over the `period` values ending `period / 2 + 1` values before it, so the
+/// slice must hold at least `period + period / 2 + 1` values. Earlier values
+/// are ignored.
+///
+/// # Arguments
+///
+/// * `slice` - array of values
+/// * `period` - number of periods of the simple moving average
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let value = momentum::detrended_price_oscillator(&[1., 3., 4., 6.], 2);
+/// assert_eq!(value.ok(), Some(4.));
+/// ```
+pub fn detrended_price_oscillator(slice: &[f64], period: usize) -> Result<f64> {
+	analysis::check_period(period)?;
+	analysis::check_finite(slice)?;
+	let displacement = period / 2 + 1;
+	let needed = period + displacement;
+	if slice.len() < needed {
+		return Err(AnalysisError::InsufficientData { needed, got: slice.len() });
+	}
+	let end = slice.len() - displacement;
+	let average = trend::simple_moving_average(&slice[end - period..end])?;
+	Ok(slice[slice.len() - 1] - average)
+}
+
+/// Streaming detrended price oscillator.
+///
+/// `Dpo` keeps the last `period / 2 + 1` simple moving averages and subtracts
+/// the oldest one from the current value. The first value is available after
+/// `period + period / 2 + 1` values. `Dpo` defaults to 20 periods.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Dpo;
+///
+/// let mut dpo = Dpo::new(2).unwrap();
+/// assert_eq!(dpo.next(1.).ok(), Some(None));
+/// assert_eq!(dpo.next(3.).ok(), Some(None));
+/// assert_eq!(dpo.next(4.).ok(), Some(None));
+/// assert_eq!(dpo.next(6.).ok(), Some(Some(4.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dpo {
+	sma: Sma,
+	averages: Ring<f64>,
+	value: Option<f64>,
+}
+
+impl Dpo {
+	/// Creates a new detrended price oscillator over `period` values.
+	pub fn new(period: usize) -> Result<Dpo> {
+		Ok(Dpo {
+			sma: Sma::new(period)?,
+			averages: Ring::new(period / 2 + 1),
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Dpo {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		let average = match self.sma.next(value)? {
+			Some(average) => average,
+			None => return Ok(None),
+		};
+		if let Some(displaced) = self.averages.push(average) {
+			self.value = Some(value - displaced);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.sma.reset();
+		self.averages.clear();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.sma.lookback() + self.averages.capacity()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for Dpo {
+	fn default() -> Dpo {
+		Dpo::new(20).unwrap()
+	}
+}
+
+/// Calculates the detrended price oscillator for every value of the series.
+///
+/// The result is aligned with the input so that the first
+/// `period + period / 2` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods of the simple moving average
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::dpo_series(&[1., 3., 4., 6., 8.], 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, None, Some(4.), Some(4.5)]));
+/// ```
+pub fn dpo_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Dpo::new(period)?, values)
+}
+
+/// Streaming true strength index (TSI) developed by William Blau.
+///
+/// The momentum, i.e. the change between consecutive closing prices, and its
+/// absolute value are both smoothed twice with exponential moving averages,
+/// first over `long` and then over `short` periods. TSI is the ratio of the
+/// two double-smoothed values multiplied by 100, so it oscillates between
+/// -100 and 100. The EMAs are seeded with simple averages, so the first value
+/// is available after `long + short` bars. `Tsi` defaults to Blau's 25 and 13
+/// periods and returns 0 while the closing prices do not change.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::Tsi;
+///
+/// let bar = |close| Bar::new(close, close, close, close, 0., 0).unwrap();
+/// let mut tsi = Tsi::new(1, 1).unwrap();
+/// assert_eq!(tsi.next(bar(10.)).ok(), Some(None));
+/// assert_eq!(tsi.next(bar(12.)).ok(), Some(Some(100.)));
+/// assert_eq!(tsi.next(bar(11.)).ok(), Some(Some(-100.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tsi {
+	momentum: (Ema, Ema),
+	absolute: (Ema, Ema),
+	previous: Option<f64>,
+	value: Option<f64>,
+}
+
+impl Tsi {
+	/// Creates a new true strength index.
+	///
+	/// # Arguments
+	///
+	/// * `long` - number of periods of the first smoothing
+	/// * `short` - number of periods of the second smoothing
+	pub fn new(long: usize, short: usize) -> Result<Tsi> {
+		Ok(Tsi {
+			momentum: (Ema::new(long)?, Ema::new(short)?),
+			absolute: (Ema::new(long)?, Ema::new(short)?),
+			previous: None,
+			value: None,
+		})
+	}
+
+	/// Returns the current index, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Tsi {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		let close = bar.close();
+		let momentum = match self.previous.replace(close) {
+			Some(previous) => close - previous,
+			None => return Ok(None),
+		};
+		let smoothed = match self.momentum.0.next(momentum)? {
+			Some(value) => self.momentum.1.next(value)?,
+			None => None,
+		};
+		let absolute = match self.absolute.0.next(momentum.abs())? {
+			Some(value) => self.absolute.1.next(value)?,
+			None => None,
+		};
+		if let (Some(smoothed), Some(absolute)) = (smoothed, absolute) {
+			self.value = Some(match absolute {
+				0. => 0.,
+				_ => 100. * smoothed / absolute,
+			});
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.momentum.0.reset();
+		self.momentum.1.reset();
+		self.absolute.0.reset();
+		self.absolute.1.reset();
+		self.previous = None;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		1 + self.momentum.0.lookback() + self.momentum.1.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for Tsi {
+	fn default() -> Tsi {
+		Tsi::new(25, 13).unwrap()
+	}
+}
+
+/// Calculates the true strength index for every bar of the series.
+///
+/// The result is aligned with the input so that the first
+/// `long + short - 1` positions are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `long` - number of periods of the first smoothing
+/// * `short` - number of periods of the second smoothing
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |close| Bar::new(close, close, close, close, 0., 0).unwrap();
+/// let bars = [bar(10.), bar(12.), bar(11.)];
+/// let values = momentum::tsi_series(&bars, 2, 1);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(33.333333333333336)]));
+/// ```
+pub fn tsi_series(bars: &[Bar], long: usize, short: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Tsi::new(long, short)?, bars)
+}
+
+/// Streaming ultimate oscillator developed by Larry Williams.
+///
+/// For every bar the buying pressure is the close minus the lower of the low
+/// and the previous close, and the true range is the higher of the high and
+/// the previous close minus that same lower value. The ratio of the averages
+/// of the buying pressure and the true range is taken over three periods and
+/// the ratios are weighted 4, 2 and 1 from the shortest period to the
+/// longest, so the output oscillates between 0 and 100. A ratio of 0.5 is
+/// used while the bars of a period have no range at all.
+///
+/// The first value is available after `long + 1` bars. `UltimateOscillator`
+/// defaults to Williams' 7, 14 and 28 periods.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::UltimateOscillator;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut ultimate = UltimateOscillator::new(1, 1, 2).unwrap();
+/// assert_eq!(ultimate.next(bar(10., 11., 9.)).ok(), Some(None));
+/// assert_eq!(ultimate.next(bar(11., 12., 10.)).ok(), Some(None));
+/// assert_eq!(ultimate.next(bar(12., 13., 11.)).ok(), Some(Some(50.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UltimateOscillator {
+	averages: [(Sma, Sma); 3],
+	previous: Option<f64>,
+	value: Option<f64>,
+}
+
+impl UltimateOscillator {
+	/// Creates a new ultimate oscillator.
+	///
+	/// # Arguments
+	///
+	/// * `short` - number of bars of the short period, weighted 4
+	/// * `medium` - number of bars of the medium period, weighted 2
+	/// * `long` - number of bars of the long period, weighted 1
+	pub fn new(short: usize, medium: usize, long: usize) -> Result<UltimateOscillator> {
+		Ok(UltimateOscillator {
+			averages: [
+				(Sma::new(short)?, Sma::new(short)?),
+				(Sma::new(medium)?, Sma::new(medium)?),
+				(Sma::new(long)?, Sma::new(long)?),
+			],
+			previous: None,
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for UltimateOscillator {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		let previous = match self.previous.replace(bar.close()) {
+			Some(previous) => previous,
+			None => return Ok(None),
+		};
+		let low = bar.low().min(previous);
+		let pressure = bar.close() - low;
+		let range = bar.high().max(previous) - low;
+		let mut ratios = [None; 3];
+		for (ratio, averages) in ratios.iter_mut().zip(self.averages.iter_mut()) {
+			let averages = (averages.0.next(pressure)?, averages.1.next(range)?);
+			if let (Some(pressure), Some(range)) = averages {
+				*ratio = Some(match range {
+					0. => 0.5,
+					_ => pressure / range,
+				});
+			}
+		}
+		if let [Some(short), Some(medium), Some(long)] = ratios {
+			self.value = Some(100. * (4. * short + 2. * medium + long) / 7.);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		for averages in &mut self.averages {
+			averages.0.reset();
+			averages.1.reset();
+		}
+		self.previous = None;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		1 + self.averages.iter().map(|averages| averages.0.lookback()).max().unwrap()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for UltimateOscillator {
+	fn default() -> UltimateOscillator {
+		UltimateOscillator::new(7, 14, 28).unwrap()
+	}
+}
+
+/// Calculates the ultimate oscillator for every bar of the series.
+///
+/// The result is aligned with the input so that the warm-up positions, before
+/// the averages of all three periods are available, are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `short` - number of bars of the short period, weighted 4
+/// * `medium` - number of bars of the medium period, weighted 2
+/// * `long` - number of bars of the long period, weighted 1
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let bars = [bar(10., 11., 9.), bar(11., 12., 10.), bar(12., 13., 11.)];
+/// let values = momentum::ultimate_oscillator_series(&bars, 1, 1, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(50.)]));
+/// ```
+pub fn ultimate_oscillator_series(bars: &[Bar], short: usize, medium: usize, long: usize)
+	-> Result<Vec<Option<f64>>> {
+	analysis::series(UltimateOscillator::new(short, medium, long)?, bars)
+}
+
+/// Streaming awesome oscillator developed by Bill Williams.
+///
+/// The awesome oscillator is the difference between a fast and a slow simple
+/// moving average of the median price `(high + low) / 2`. Crossings of the
+/// zero line and changes of its direction are used as signals.
+/// `AwesomeOscillator` defaults to Williams' 5 and 34 periods.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::AwesomeOscillator;
+///
+/// let bar = |high, low| Bar::new(low, high, low, low, 0., 0).unwrap();
+/// let mut awesome = AwesomeOscillator::new(1, 2).unwrap();
+/// assert_eq!(awesome.next(bar(11., 9.)).ok(), Some(None));
+/// assert_eq!(awesome.next(bar(13., 11.)).ok(), Some(Some(1.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AwesomeOscillator {
+	fast: Sma,
+	slow: Sma,
+	value: Option<f64>,
+}
+
+impl AwesomeOscillator {
+	/// Creates a new awesome oscillator.
+	///
+	/// # Arguments
+	///
+	/// * `fast` - number of bars of the fast average
+	/// * `slow` - number of bars of the slow average
+	pub fn new(fast: usize, slow: usize) -> Result<AwesomeOscillator> {
+		Ok(AwesomeOscillator {
+			fast: Sma::new(fast)?,
+			slow: Sma::new(slow)?,
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for AwesomeOscillator {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		let median = (bar.high() + bar.low()) / 2.;
+		let averages = (self.fast.next(median)?, self.slow.next(median)?);
+		if let (Some(fast), Some(slow)) = averages {
+			self.value = Some(fast - slow);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.fast.reset();
+		self.slow.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.fast.lookback().max(self.slow.lookback())
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for AwesomeOscillator {
+	fn default() -> AwesomeOscillator {
+		AwesomeOscillator::new(5, 34).unwrap()
+	}
+}
+
+/// Calculates the awesome oscillator for every bar of the series.
+///
+/// The result is aligned with the input so that the first
+/// `max(fast, slow) - 1` positions are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `fast` - number of bars of the fast average
+/// * `slow` - number of bars of the slow average
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |high, low| Bar::new(low, high, low, low, 0., 0).unwrap();
+/// let bars = [bar(11., 9.), bar(13., 11.), bar(12., 10.)];
+/// let values = momentum::awesome_oscillator_series(&bars, 1, 2);
+/// assert_eq!(values.ok(), Some(vec![None, Some(1.), Some(-0.5)]));
+/// ```
+pub fn awesome_oscillator_series(bars: &[Bar], fast: usize, slow: usize)
+	-> Result<Vec<Option<f64>>> {
+	analysis::series(AwesomeOscillator::new(fast, slow)?, bars)
+}
+
+/// Streaming accelerator oscillator developed by Bill Williams.
+///
+/// The accelerator oscillator is the awesome oscillator minus its own simple
+/// moving average over `signal` bars. It measures the acceleration of the
+/// momentum and is expected to change direction before the awesome
+/// oscillator does. `AcceleratorOscillator` defaults to Williams' 5 and 34
+/// periods for the awesome oscillator and 5 periods for the signal.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::AcceleratorOscillator;
+///
+/// let bar = |high, low| Bar::new(low, high, low, low, 0., 0).unwrap();
+/// let mut accelerator = AcceleratorOscillator::new(1, 2, 2).unwrap();
+/// assert_eq!(accelerator.next(bar(11., 9.)).ok(), Some(None));
+/// assert_eq!(accelerator.next(bar(13., 11.)).ok(), Some(None));
+/// assert_eq!(accelerator.next(bar(12., 10.)).ok(), Some(Some(-0.75)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AcceleratorOscillator {
+	awesome: AwesomeOscillator,
+	signal: Sma,
+	value: Option<f64>,
+}
+
+impl AcceleratorOscillator {
+	/// Creates a new accelerator oscillator.
+	///
+	/// # Arguments
+	///
+	/// * `fast` - number of bars of the fast average
+	/// * `slow` - number of bars of the slow average
+	/// * `signal` - number of periods of the awesome oscillator average
+	pub fn new(fast: usize, slow: usize, signal: usize) -> Result<AcceleratorOscillator> {
+		Ok(AcceleratorOscillator {
+			awesome: AwesomeOscillator::new(fast, slow)?,
+			signal: Sma::new(signal)?,
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for AcceleratorOscillator {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		if let Some(awesome) = self.awesome.next(bar)? {
+			if let Some(signal) = self.signal.next(awesome)? {
+				self.value = Some(awesome - signal);
+			}
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.awesome.reset();
+		self.signal.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.awesome.lookback() + self.signal.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for AcceleratorOscillator {
+	fn default() -> AcceleratorOscillator {
+		AcceleratorOscillator::new(5, 34, 5).unwrap()
+	}
+}
+
+/// Calculates the accelerator oscillator for every bar of the series.
+///
+/// The result is aligned with the input so that the first
+/// `max(fast, slow) + signal - 2` positions are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `fast` - number of bars of the fast average
+/// * `slow` - number of bars of the slow average
+/// * `signal` - number of periods of the awesome oscillator average
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |high, low| Bar::new(low, high, low, low, 0., 0).unwrap();
+/// let bars = [bar(11., 9.), bar(13., 11.), bar(12., 10.)];
+/// let values = momentum::accelerator_oscillator_series(&bars, 1, 2, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(-0.75)]));
+/// ```
+pub fn accelerator_oscillator_series(bars: &[Bar], fast: usize, slow: usize, signal: usize)
+	-> Result<Vec<Option<f64>>> {
+	analysis::series(AcceleratorOscillator::new(fast, slow, signal)?, bars)
+}
+
+/// Streaming Know Sure Thing (KST) developed by Martin Pring.
+///
+/// KST combines four rates of change of increasing length, each smoothed with
+/// its own simple moving average, into a weighted sum where the weights are 1,
+/// 2, 3 and 4 from the shortest rate of change to the longest. A simple moving
+/// average of KST over `signal` values is used as the signal line.
+///
+/// `Kst` defaults to Pring's daily parameters: rates of change over 10, 15, 20
+/// and 30 periods smoothed over 10, 10, 10 and 15 periods, and a signal line
+/// over 9 periods.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Kst;
+///
+/// let mut kst = Kst::new([1, 1, 1, 1], [1, 1, 1, 1], 1).unwrap();
+/// assert_eq!(kst.next(50.).ok(), Some(None));
+/// assert_eq!(kst.next(55.).ok(), Some(Some((100., 100.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kst {
+	rates: [(Roc, Sma); 4],
+	signal: Sma,
+	value: Option<(f64, f64)>,
+}
+
+impl Kst {
+	/// Creates a new Know Sure Thing.
+	///
+	/// # Arguments
+	///
+	/// * `roc_periods` - number of periods of the four rates of change
+	/// * `sma_periods` - number of periods smoothing each rate of change
+	/// * `signal` - number of periods of the signal line
+	pub fn new(roc_periods: [usize; 4], sma_periods: [usize; 4], signal: usize) -> Result<Kst> {
+		let rate = |i: usize| -> Result<(Roc, Sma)> {
+			Ok((Roc::new(roc_periods[i])?, Sma::new(sma_periods[i])?))
+		};
+		Ok(Kst {
+			rates: [rate(0)?, rate(1)?, rate(2)?, rate(3)?],
+			signal: Sma::new(signal)?,
+			value: None,
+		})
+	}
+
+	/// Returns the current `(kst, signal)` pair, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, f64)> {
+		self.value
+	}
+}
+
+impl Indicator for Kst {
+	type Input = f64;
+	type Output = (f64, f64);
+
+	fn next(&mut self, value: f64) -> Result<Option<(f64, f64)>> {
+		let mut kst = Some(0.);
+		for (i, (roc, sma)) in self.rates.iter_mut().enumerate() {
+			let smoothed = match roc.next(value)? {
+				Some(rate) => sma.next(rate)?,
+				None => None,
+			};
+			kst = match (kst, smoothed) {
+				(Some(kst), Some(smoothed)) => Some(kst + (i + 1) as f64 * smoothed),
+				_ => None,
+			};
+		}
+		if let Some(kst) = kst {
+			if let Some(signal) = self.signal.next(kst)? {
+				self.value = Some((kst, signal));
+			}
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		for (roc, sma) in &mut self.rates {
+			roc.reset();
+			sma.reset();
+		}
+		self.signal.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		let rates = self.rates.iter().map(|(roc, sma)| roc.lookback() + sma.lookback());
+		rates.max().unwrap() + self.signal.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for Kst {
+	fn default() -> Kst {
+		Kst::new([10, 15, 20, 30], [10, 10, 10, 15], 9).unwrap()
+	}
+}
+
+/// Calculates the Know Sure Thing and its signal line for every value of the
+/// series.
+///
+/// The result is aligned with the input so that the warm-up positions, before
+/// the first `(kst, signal)` pair is available, are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `roc_periods` - number of periods of the four rates of change
+/// * `sma_periods` - number of periods smoothing each rate of change
+/// * `signal` - number of periods of the signal line
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::kst_series(&[50., 55., 44.], [1, 1, 1, 1], [1, 1, 1, 1], 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some((-200., -50.))]));
+/// ```
+pub fn kst_series(values: &[f64], roc_periods: [usize; 4], sma_periods: [usize; 4],
+	signal: usize) -> Result<Vec<Option<(f64, f64)>>> {
+	analysis::series(Kst::new(roc_periods, sma_periods, signal)?, values)
+}
+
+/// Streaming Coppock curve developed by Edwin Coppock.
+///
+/// The Coppock curve is a weighted moving average of the sum of a long and a
+/// short rate of change. Coppock designed it for monthly closing prices of a
+/// stock index, where a turn upwards from below zero is read as a long-term
+/// buy signal. `Coppock` defaults to his 14 and 11 months for the rates of
+/// change and 10 months for the weighted moving average.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::Coppock;
+///
+/// let mut coppock = Coppock::new(2, 1, 1).unwrap();
+/// assert_eq!(coppock.next(50.).ok(), Some(None));
+/// assert_eq!(coppock.next(40.).ok(), Some(None));
+/// assert_eq!(coppock.next(60.).ok(), Some(Some(70.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Coppock {
+	long: Roc,
+	short: Roc,
+	wma: Wma,
+	value: Option<f64>,
+}
+
+impl Coppock {
+	/// Creates a new Coppock curve.
+	///
+	/// # Arguments
+	///
+	/// * `long` - number of periods of the long rate of change
+	/// * `short` - number of periods of the short rate of change
+	/// * `wma` - number of periods of the weighted moving average
+	pub fn new(long: usize, short: usize, wma: usize) -> Result<Coppock> {
+		Ok(Coppock {
+			long: Roc::new(long)?,
+			short: Roc::new(short)?,
+			wma: Wma::new(wma)?,
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Coppock {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		let rates = (self.long.next(value)?, self.short.next(value)?);
+		if let (Some(long), Some(short)) = rates {
+			if let Some(curve) = self.wma.next(long + short)? {
+				self.value = Some(curve);
+			}
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.long.reset();
+		self.short.reset();
+		self.wma.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.long.lookback().max(self.short.lookback()) + self.wma.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for Coppock {
+	fn default() -> Coppock {
+		Coppock::new(14, 11, 10).unwrap()
+	}
+}
+
+/// Calculates the Coppock curve for every value of the series.
+///
+/// The result is aligned with the input so that the first
+/// `max(long, short) + wma - 1` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `long` - number of periods of the long rate of change
+/// * `short` - number of periods of the short rate of change
+/// * `wma` - number of periods of the weighted moving average
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum;
+///
+/// let values = momentum::coppock_series(&[50., 40., 60., 45.], 2, 1, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, None, Some(15.)]));
+/// ```
+pub fn coppock_series(values: &[f64], long: usize, short: usize, wma: usize)
+	-> Result<Vec<Option<f64>>> {
+	analysis::series(Coppock::new(long, short, wma)?, values)
+}
+
+/// Streaming Elder Ray developed by Alexander Elder.
+///
+/// Bull power is the high minus an exponential moving average of the closing
+/// prices and bear power is the low minus the same average. They show how far
+/// buyers and sellers were able to push the price away from the consensus of
+/// value represented by the average. `ElderRay` defaults to Elder's 13
+/// periods.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::momentum::ElderRay;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut elder_ray = ElderRay::new(2).unwrap();
+/// assert_eq!(elder_ray.next(bar(10., 11., 9.)).ok(), Some(None));
+/// assert_eq!(elder_ray.next(bar(12., 13., 10.)).ok(), Some(Some((2., -1.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElderRay {
+	ema: Ema,
+	value: Option<(f64, f64)>,
+}
+
+impl ElderRay {
+	/// Creates a new Elder Ray over `period` bars.
+	pub fn new(period: usize) -> Result<ElderRay> {
+		Ok(ElderRay {
+			ema: Ema::new(period)?,
+			value: None,
+		})
+	}
+
+	/// Returns the current `(bull, bear)` power pair, or `None` during
+	/// warm-up.
+	pub fn current(&self) -> Option<(f64, f64)> {
+		self.value
+	}
+}
+
+impl Indicator for ElderRay {
+	type Input = Bar;
+	type Output = (f64, f64);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, f64)>> {
+		if let Some(average) = self.ema.next(bar.close())? {
+			self.value = Some((bar.high() - average, bar.low() - average));
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.ema.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.ema.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for ElderRay {
+	fn default() -> ElderRay {
+		ElderRay::new(13).unwrap()
+	}
+}
+
+/// Calculates the Elder Ray bull and bear power for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars of the exponential moving average
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Bar;
+/// use stat::analysis::momentum;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let bars = [bar(10., 11., 9.), bar(12., 13., 10.)];
+/// let values = momentum::elder_ray_series(&bars, 2);
+/// assert_eq!(values.ok(), Some(vec![None, Some((2., -1.))]));
+/// ```
+pub fn elder_ray_series(bars: &[Bar], period: usize) -> Result<Vec<Option<(f64, f64)>>> {
+	analysis::series(ElderRay::new(period)?, bars)
+}
+
+/// Classification of a bar by Elder's impulse system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Impulse {
+	/// Both the average and the MACD histogram rise, shown as a green bar.
+	Bullish,
+	/// Both the average and the MACD histogram fall, shown as a red bar.
+	Bearish,
+	/// The average and the MACD histogram disagree, shown as a blue bar.
+	Neutral,
+}
+
+/// Streaming impulse system developed by Alexander Elder.
+///
+/// The impulse system combines the slope of an exponential moving average,
+/// which shows the trend, with the slope of the MACD histogram, which shows
+/// the momentum. A bar is bullish when both rise since the previous value,
+/// bearish when both fall and neutral otherwise. `ElderImpulse` defaults to
+/// Elder's 13 periods for the average and the standard 12, 26 and 9 periods
+/// for MACD.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::momentum::{ElderImpulse, Impulse};
+///
+/// let mut impulse = ElderImpulse::new(1, 1, 2, 2).unwrap();
+/// assert_eq!(impulse.next(10.).ok(), Some(None));
+/// assert_eq!(impulse.next(11.).ok(), Some(None));
+/// assert_eq!(impulse.next(12.).ok(), Some(None));
+/// assert_eq!(impulse.next(16.).ok(), Some(Some(Impulse::Bullish)));
+/// assert_eq!(impulse.next(13.).ok(), Some(Some(Impulse::Bearish)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElderImpulse {
+	ema: Ema,
+	macd: Macd,
+	previous: Option<(f64, f64)>,
+	value: Option<Impulse>,
+}
+
+impl ElderImpulse {
+	/// Creates a new impulse system.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of periods of the exponential moving average
+	/// * `fast` - number of periods of the fast MACD average
+	/// * `slow` - number of periods of the slow MACD average
+	/// * `signal` - number of periods of the MACD signal line
+	pub fn new(period: usize, fast: usize, slow: usize, signal: usize) -> Result<ElderImpulse> {
+		Ok(ElderImpulse {
+			ema: Ema::new(period)?,
+			macd: Macd::new(fast, slow, signal)?,
+			previous: None,
+			value: None,
+		})
+	}
+
+	/// Returns the current classification, or `None` during warm-up.
+	pub fn current(&self) -> Option<Impulse> {
+		self.value
+	}
+}
+
+impl Indicator for ElderImpulse {
+	type Input = f64;
+	type Output = Impulse;
+
+	fn next(&mut self, close: f64) -> Result<Option<Impulse>> {
+		let average = self.ema.next(close)?;
+		let histogram = self.macd.next(close)?.map(|(_, _, histogram)| histogram);
+		let current = match (average, histogram) {
+			(Some(average), Some(histogram)) => (average, histogram),
+			_ => return Ok(None),
+		};
+		if let Some(previous) = self.previous.replace(current) {
+			self.value = Some(match (current.0 - previous.0, current.1 - previous.1) {
+				(trend, momentum) if trend > 0. && momentum > 0. => Impulse::Bullish,
+				(trend, momentum) if trend < 0. && momentum < 0. => Impulse::Bearish,
+				_ => Impulse::Neutral,
+			});
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.ema.reset();
+		self.macd.reset();
+		self.previous = None;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.ema.lookback().max(self.macd.lookback()) + 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+impl Default for ElderImpulse {
+	fn default() -> ElderImpulse {
+		ElderImpulse::new(13, 12, 26, 9).unwrap()
+	}
+}
+
+/// Calculates the impulse system classification for every closing price of
+/// the series.
+///
+/// The result is aligned with the input so that the warm-up positions, before
+/// both the average and the MACD histogram have a previous value, are `None`.
+///
+/// # Arguments
+///
+/// * `closes` - array of closing prices
+/// * `period` - number of periods of the exponential moving average
+/// * `fast` - number of periods of the fast MACD average
+/// * `slow` - number of periods of the slow MACD average
+/// * `signal` - number of periods of the MACD signal line
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::momentum::{self, Impulse};
+///
+/// let values = momentum::elder_impulse_series(&[10., 11., 12., 16., 13.], 1, 1, 2, 2);
+/// let values = values.unwrap();
+/// assert_eq!(values[..3], [None; 3]);
+/// assert_eq!(values[3..], [Some(Impulse::Bullish), Some(Impulse::Bearish)]);
+/// ```
+pub fn elder_impulse_series(closes: &[f64], period: usize, fast: usize, slow: usize,
+	signal: usize) -> Result<Vec<Option<Impulse>>> {
+	analysis::series(ElderImpulse::new(period, fast, slow, signal)?, closes)
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate math;
+	use analysis::{AnalysisError, Bar, Indicator, Result};
+	use self::math::round::half_up;
+
+	fn to_bars(data: &[(f64, f64, f64)]) -> Vec<Bar> {
+		data.iter()
+			.map(|&(close, high, low)| Bar::new(close, high, low, close, 0., 0).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn relative_strength_index() {
+		let tests: [(f64, f64, Result<f64>); 26] = [
+			(0.238386, 0.099593, Ok(70.532785)),
+			(0.221358, 0.112422, Ok(66.318533)),
+			(0.207690, 0.104392, Ok(66.549817)),
+			(0.219912, 0.096935, Ok(69.406370)),
+			(0.204204, 0.103540, Ok(66.355152)),
+			(0.189618, 0.137451, Ok(57.974923)),
+			(0.216667, 0.127633, Ok(62.929712)),
+			(0.204040, 0.118517, Ok(63.257037)),
+			(0.189466, 0.148508, Ok(56.059342)),
+			(0.228633, 0.137901, Ok(62.377024)),
+			(0.212302, 0.175765, Ok(54.707563)),
+			(0.197137, 0.193832, Ok(50.422668)),
+			(0.183056, 0.274701, Ok(39.989776)),
+			(0.180659, 0.255079, Ok(41.460465)),
+			(0.170598, 0.236859, Ok(41.868958)),
+			(0.183348, 0.219941, Ok(45.463179)),
+			(0.170252, 0.286138, Ok(37.304060)),
+			(0.158091, 0.319821, Ok(33.079521)),
+			(0.180270, 0.296977, Ok(37.772893)),
+
+			(1., 0., Ok(100.)),
+			(0., 1., Ok(0.)),
+			(1., 1., Ok(50.)),
+			(-1., 0., Err(AnalysisError::GainLessThanZero)),
+			(0., -1., Err(AnalysisError::LossLessThanZero)),
+			(f64::NAN, 1., Err(AnalysisError::NonFiniteInput { index: 0 })),
+			(1., f64::INFINITY, Err(AnalysisError::NonFiniteInput { index: 1 })),
+		];
+
+		for test in &tests {
+			let result = super::relative_strength_index(test.0, test.1);
+			match (result, test.2.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert_eq!(half_up(val, 6), *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn rsi() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [f64; 19] = [
+			70.532789, 66.318562, 66.549830, 69.406305, 66.355169, 57.974856,
+			62.929607, 63.257148, 56.059299, 62.377071, 54.707573, 50.422774,
+			39.989823, 41.460482, 41.868916, 45.463212, 37.304042, 33.079523,
+			37.772952,
+		];
+
+		let mut rsi = super::Rsi::default();
+		assert_eq!(rsi.lookback(), 14);
+		for (i, close) in closes.iter().enumerate() {
+			let result = rsi.next(*close).unwrap();
+			assert_eq!(result, rsi.current());
+			match i.checked_sub(14) {
+				Some(j) => {
+					assert!(rsi.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!rsi.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		match rsi.next(f64::NAN) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::NonFiniteInput { index: 0 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		assert!(super::Rsi::new(0).is_err());
+
+		rsi.reset();
+		assert!(!rsi.is_ready());
+		assert_eq!(rsi.feed(&closes[..14]).unwrap(), None);
+		let result = rsi.feed(&closes[14..]).unwrap();
+		assert_eq!(half_up(result.unwrap(), 6), results[18]);
+		match rsi.feed(&[44., 45., f64::NAN]) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::NonFiniteInput { index: 2 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+	}
+
+	#[test]
+	fn rsi_series() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [f64; 19] = [
+			70.532789, 66.318562, 66.549830, 69.406305, 66.355169, 57.974856,
+			62.929607, 63.257148, 56.059299, 62.377071, 54.707573, 50.422774,
+			39.989823, 41.460482, 41.868916, 45.463212, 37.304042, 33.079523,
+			37.772952,
+		];
+
+		let series = super::rsi_series(&closes, 14).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..14], [None; 14]);
+		for (value, exp) in series[14..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+
+		let tests: [(&[f64], usize, AnalysisError); 3] = [
+			(&closes[..14], 14, AnalysisError::InsufficientData { needed: 15, got: 14 }),
+			(&closes, 0, AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., f64::NAN], 1, AnalysisError::NonFiniteInput { index: 2 }),
+		];
+
+		for test in &tests {
+			match super::rsi_series(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn stochastic_oscillator() {
+		let tests: [(f64, f64, f64, Result<f64>); 25] = [
+			(127.2876, 128.4317, 124.5615, Ok(70.438220)),
+			(127.1781, 128.4317, 124.5615, Ok(67.608909)),
+			(128.0138, 128.4317, 124.5615, Ok(89.202108)),
+			(127.1085, 128.4317, 124.5615, Ok(65.810552)),
+			(127.7253, 128.4317, 124.5615, Ok(81.747713)),
+			(127.0587, 128.4317, 124.5615, Ok(64.523797)),
+			(127.3273, 128.2725, 124.5615, Ok(74.529776)),
+			(128.7103, 128.7700, 124.5615, Ok(98.581442)),
+			(127.8745, 129.2873, 124.5615, Ok(70.104533)),
+			(128.5809, 130.0633, 124.5615, Ok(73.056091)),
+			(128.6008, 130.0633, 124.5615, Ok(73.417791)),
+			(127.9342, 130.0633, 124.5715, Ok(61.231290)),
+			(128.1133, 130.0633, 125.0689, Ok(60.956271)),
+			(127.5960, 130.0633, 125.9245, Ok(40.386102)),
+			(127.5960, 130.0633, 125.9245, Ok(40.386102)),
 			(128.6904, 130.0633, 125.9245, Ok(66.828549)),
 			(128.2725, 130.0633, 125.9245, Ok(56.731420)),
 
-			(100., 100., 100., Ok(50.)),
-			(100., 100., 0., Ok(100.)),
-			(0., 100., 0., Ok(0.)),
-			(100., 0., 100., Err(AnalysisError::HighLessThanLow)),
-			(100., 0., 0., Err(AnalysisError::CloseGreaterThanHigh)),
-			(0., 100., 100., Err(AnalysisError::CloseLessThanLow)),
+			(100., 100., 100., Ok(50.)),
+			(100., 100., 0., Ok(100.)),
+			(0., 100., 0., Ok(0.)),
+			(100., 0., 100., Err(AnalysisError::HighLessThanLow)),
+			(100., 0., 0., Err(AnalysisError::CloseGreaterThanHigh)),
+			(0., 100., 100., Err(AnalysisError::CloseLessThanLow)),
+			(f64::NAN, 100., 0., Err(AnalysisError::NonFiniteInput { index: 0 })),
+			(50., 100., f64::NEG_INFINITY, Err(AnalysisError::NonFiniteInput { index: 2 })),
+		];
+
+		for test in &tests {
+			let result = super::stochastic_oscillator(test.0, test.1, test.2);
+			match (result, test.3.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert_eq!(half_up(val, 6), *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn stochastic() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, f64); 12] = [
+			(33.071429, 39.477954), (28.796296, 32.087302),
+			(31.619876, 31.162534), (20.594235, 27.003469),
+			(39.426144, 30.546752), (63.900970, 41.307116),
+			(83.735800, 62.354305), (84.680654, 77.439141),
+			(74.824378, 81.080277), (65.369299, 74.958110),
+			(35.962907, 58.718861), (39.763882, 47.032029),
+		];
+
+		let mut stochastic = super::Stochastic::new(5, 3, 3).unwrap();
+		assert_eq!(stochastic.lookback(), 8);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = stochastic.next(*bar).unwrap();
+			assert_eq!(result, stochastic.current());
+			match i.checked_sub(8) {
+				Some(j) => {
+					let (k, d) = result.unwrap();
+					assert!(stochastic.is_ready());
+					assert_eq!((half_up(k, 6), half_up(d, 6)), results[j]);
+				},
+				None => {
+					assert!(!stochastic.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		stochastic.reset();
+		assert!(!stochastic.is_ready());
+		assert_eq!(stochastic.current(), None);
+	}
+
+	#[test]
+	fn stochastic_series() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, f64); 12] = [
+			(33.071429, 39.477954), (28.796296, 32.087302),
+			(31.619876, 31.162534), (20.594235, 27.003469),
+			(39.426144, 30.546752), (63.900970, 41.307116),
+			(83.735800, 62.354305), (84.680654, 77.439141),
+			(74.824378, 81.080277), (65.369299, 74.958110),
+			(35.962907, 58.718861), (39.763882, 47.032029),
+		];
+
+		let series = super::stochastic_series(&bars, 5, 3, 3).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..8], [None; 8]);
+		for (value, exp) in series[8..].iter().zip(results.iter()) {
+			let (k, d) = value.unwrap();
+			assert_eq!((half_up(k, 6), half_up(d, 6)), *exp);
+		}
+
+		let tests: [(usize, usize, usize, usize, AnalysisError); 4] = [
+			(8, 5, 3, 3, AnalysisError::InsufficientData { needed: 9, got: 8 }),
+			(20, 0, 3, 3, AnalysisError::InvalidPeriod(0)),
+			(20, 5, 0, 3, AnalysisError::InvalidPeriod(0)),
+			(20, 5, 3, 0, AnalysisError::InvalidPeriod(0)),
+		];
+
+		for test in &tests {
+			match super::stochastic_series(&bars[..test.0], test.1, test.2, test.3) {
+				Err(err) => assert_eq!(err.to_string(), test.4.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn williams_percent_r() {
+		let tests: [(f64, f64, f64, Result<f64>); 25] = [
+			(127.2876, 128.4317, 124.5615, Ok(-29.561780)),
+			(127.1781, 128.4317, 124.5615, Ok(-32.391091)),
+			(128.0138, 128.4317, 124.5615, Ok(-10.797891)),
+			(127.1085, 128.4317, 124.5615, Ok(-34.189447)),
+			(127.7253, 128.4317, 124.5615, Ok(-18.252287)),
+			(127.0587, 128.4317, 124.5615, Ok(-35.476203)),
+			(127.3273, 128.2725, 124.5615, Ok(-25.470224)),
+			(128.7103, 128.7700, 124.5615, Ok(-1.418558)),
+			(127.8745, 129.2873, 124.5615, Ok(-29.895467)),
+			(128.5809, 130.0633, 124.5615, Ok(-26.943909)),
+			(128.6008, 130.0633, 124.5615, Ok(-26.582209)),
+			(127.9342, 130.0633, 124.5715, Ok(-38.768710)),
+			(128.1133, 130.0633, 125.0689, Ok(-39.043729)),
+			(127.5960, 130.0633, 125.9245, Ok(-59.613898)),
+			(127.5960, 130.0633, 125.9245, Ok(-59.613898)),
+			(128.6904, 130.0633, 125.9245, Ok(-33.171451)),
+			(128.2725, 130.0633, 125.9245, Ok(-43.268580)),
+
+			(100., 100., 100., Ok(-50.)),
+			(100., 100., 0., Ok(0.)),
+			(0., 100., 0., Ok(-100.)),
+			(100., 0., 100., Err(AnalysisError::HighLessThanLow)),
+			(100., 0., 0., Err(AnalysisError::CloseGreaterThanHigh)),
+			(0., 100., 100., Err(AnalysisError::CloseLessThanLow)),
+			(f64::NAN, 100., 0., Err(AnalysisError::NonFiniteInput { index: 0 })),
+			(50., 100., f64::NEG_INFINITY, Err(AnalysisError::NonFiniteInput { index: 2 })),
+		];
+
+		for test in &tests {
+			let result = super::williams_percent_r(test.0, test.1, test.2);
+			match (result, test.3.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert_eq!(half_up(val, 6), *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn williams_r() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 16] = [
+			-41.666667, -47.142857, -58.285714, -91.388889, -51.111111,
+			-71.111111, -82.918149, -84.188034, -14.615385, -9.493671,
+			-24.683544, -11.780822, -39.062500, -53.048780, -100.000000,
+			-27.659574,
+		];
+
+		let mut williams_r = super::WilliamsR::new(5).unwrap();
+		assert_eq!(williams_r.lookback(), 4);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = williams_r.next(*bar).unwrap();
+			assert_eq!(result, williams_r.current());
+			match i.checked_sub(4) {
+				Some(j) => {
+					assert!(williams_r.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!williams_r.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		williams_r.reset();
+		assert!(!williams_r.is_ready());
+		assert_eq!(williams_r.current(), None);
+
+		let series = super::williams_r_series(&bars, 5).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..4], [None; 4]);
+		for (value, exp) in series[4..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+
+		let flat = to_bars(&[(100., 100., 100.); 3]);
+		let series = super::williams_r_series(&flat, 2).unwrap();
+		assert_eq!(series, vec![None, Some(-50.), Some(-50.)]);
+
+		let tests: [(usize, usize, AnalysisError); 2] = [
+			(4, 5, AnalysisError::InsufficientData { needed: 5, got: 4 }),
+			(20, 0, AnalysisError::InvalidPeriod(0)),
+		];
+
+		for test in &tests {
+			match super::williams_r_series(&bars[..test.0], test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn rate_of_change() {
+		let tests: [(f64, f64, Result<f64>); 7] = [
+			(45.8931, 44.3389, Ok(3.505274)),
+			(43.4205, 45.8931, Ok(-5.387738)),
+			(55., 50., Ok(10.)),
+			(10., 0., Ok(0.)),
+			(0., 10., Ok(-100.)),
+			(f64::NAN, 1., Err(AnalysisError::NonFiniteInput { index: 0 })),
+			(1., f64::INFINITY, Err(AnalysisError::NonFiniteInput { index: 1 })),
+		];
+
+		for test in &tests {
+			let result = super::rate_of_change(test.0, test.1);
+			match (result, test.2.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert!((val - *exp).abs() < 5e-7),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+
+		let tests: [(f64, f64, Result<f64>); 4] = [
+			(45.8931, 44.3389, Ok(1.035053)),
+			(55., 50., Ok(1.1)),
+			(10., 0., Ok(1.)),
+			(1., f64::NAN, Err(AnalysisError::NonFiniteInput { index: 1 })),
+		];
+
+		for test in &tests {
+			let result = super::rate_of_change_ratio(test.0, test.1);
+			match (result, test.2.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert_eq!(half_up(val, 6), *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn chande_momentum_oscillator() {
+		let tests: [(f64, f64, Result<f64>); 8] = [
+			(5.5, 2.5, Ok(37.5)),
+			(2.5, 5.5, Ok(-37.5)),
+			(1., 0., Ok(100.)),
+			(0., 0., Ok(0.)),
+			(-1., 0., Err(AnalysisError::GainLessThanZero)),
+			(0., -1., Err(AnalysisError::LossLessThanZero)),
+			(f64::NAN, 1., Err(AnalysisError::NonFiniteInput { index: 0 })),
+			(1., f64::INFINITY, Err(AnalysisError::NonFiniteInput { index: 1 })),
+		];
+
+		for test in &tests {
+			let result = super::chande_momentum_oscillator(test.0, test.1);
+			match (result, test.2.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert_eq!(val, *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn rate_of_change_indicators() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let momentum: [f64; 23] = [
+			1.5542, 1.9426, 1.4643, 2.6696, 1.9542, 1.1764, 0.9373, 0.9871,
+			0.3789, -0.4387, 0.3191, 0.2193, 0.0997, 0.1695, -0.4985, -0.6480,
+			-2.0040, -2.2333, -2.0041, -1.0767, -2.7917, -3.5893, -2.5823,
+		];
+		let roc: [f64; 23] = [
+			3.505274, 4.405968, 3.316670, 6.121195, 4.408520, 2.624346,
+			2.078478, 2.173056, 0.826511, -0.951986, 0.695311, 0.476399,
+			0.218573, 0.366233, -1.077093, -1.408610, -4.353418, -4.811944,
+			-4.335795, -2.358913, -6.041045, -7.760296, -5.648854,
+		];
+		let rocr: [f64; 23] = [
+			1.035053, 1.044060, 1.033167, 1.061212, 1.044085, 1.026243,
+			1.020785, 1.021731, 1.008265, 0.990480, 1.006953, 1.004764,
+			1.002186, 1.003662, 0.989229, 0.985914, 0.956466, 0.951881,
+			0.956642, 0.976411, 0.939590, 0.922397, 0.943511,
+		];
+
+		let mut indicator = super::Momentum::new(10).unwrap();
+		assert_eq!(indicator.lookback(), 10);
+		for (i, close) in closes.iter().enumerate() {
+			let result = indicator.next(*close).unwrap();
+			assert_eq!(result, indicator.current());
+			assert_eq!(indicator.is_ready(), i >= 10);
+			match i.checked_sub(10) {
+				Some(j) => assert!((result.unwrap() - momentum[j]).abs() < 1e-9),
+				None => assert_eq!(result, None),
+			}
+		}
+		indicator.reset();
+		assert!(!indicator.is_ready());
+		assert_eq!(indicator.current(), None);
+
+		let mut indicator = super::Roc::new(10).unwrap();
+		assert_eq!(indicator.lookback(), 10);
+		for (i, close) in closes.iter().enumerate() {
+			let result = indicator.next(*close).unwrap();
+			assert_eq!(result, indicator.current());
+			match i.checked_sub(10) {
+				Some(j) => assert!((result.unwrap() - roc[j]).abs() < 5e-7),
+				None => assert_eq!(result, None),
+			}
+		}
+		match indicator.next(f64::NAN) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::NonFiniteInput { index: 0 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+
+		let mut indicator = super::Rocr::new(10).unwrap();
+		assert_eq!(indicator.lookback(), 10);
+		for (i, close) in closes.iter().enumerate() {
+			let result = indicator.next(*close).unwrap();
+			assert_eq!(result, indicator.current());
+			match i.checked_sub(10) {
+				Some(j) => assert_eq!(half_up(result.unwrap(), 6), rocr[j]),
+				None => assert_eq!(result, None),
+			}
+		}
+		indicator.reset();
+		assert!(!indicator.is_ready());
+
+		let series = super::momentum_series(&closes, 10).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(momentum.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 1e-9);
+		}
+		let series = super::roc_series(&closes, 10).unwrap();
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(roc.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+		let series = super::rocr_series(&closes, 10).unwrap();
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(rocr.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+
+		let tests: [(&[f64], usize, AnalysisError); 3] = [
+			(&closes[..10], 10, AnalysisError::InsufficientData { needed: 11, got: 10 }),
+			(&closes, 0, AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., f64::NAN], 1, AnalysisError::NonFiniteInput { index: 2 }),
+		];
+
+		for test in &tests {
+			for result in &[
+				super::momentum_series(test.0, test.1),
+				super::roc_series(test.0, test.1),
+				super::rocr_series(test.0, test.1),
+			] {
+				match result {
+					Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+					_ => panic!("return type mismatch"),
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn cmo() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [f64; 24] = [
+			52.589197, 55.363120, 56.436000, 62.196259, 61.631134, 54.471971,
+			33.825448, 25.523434, 24.252123, 6.087032, -9.290881, 5.767005,
+			23.357370, -21.838374, 5.074698, -5.881091, -16.426010, -46.953575,
+			-40.594649, -31.709812, -38.459740, -52.592868, -54.448272,
+			-62.242928,
+		];
+
+		let mut cmo = super::Cmo::new(9).unwrap();
+		assert_eq!(cmo.lookback(), 9);
+		for (i, close) in closes.iter().enumerate() {
+			let result = cmo.next(*close).unwrap();
+			assert_eq!(result, cmo.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(cmo.is_ready());
+					assert!((result.unwrap() - results[j]).abs() < 5e-7);
+				},
+				None => {
+					assert!(!cmo.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		cmo.reset();
+		assert!(!cmo.is_ready());
+		assert_eq!(cmo.current(), None);
+
+		let series = super::cmo_series(&closes, 9).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+
+		let series = super::cmo_series(&[10., 10., 10., 11.], 2).unwrap();
+		assert_eq!(series, vec![None, None, Some(0.), Some(100.)]);
+
+		let tests: [(&[f64], usize, AnalysisError); 3] = [
+			(&closes[..9], 9, AnalysisError::InsufficientData { needed: 10, got: 9 }),
+			(&closes, 0, AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., f64::NAN], 1, AnalysisError::NonFiniteInput { index: 2 }),
 		];
 
 		for test in &tests {
-			let result = super::stochastic_oscillator(test.0, test.1, test.2);
-			match (result, test.3.as_ref()) {
+			match super::cmo_series(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn commodity_channel_index() {
+		let tests: [(&[f64], f64, Result<f64>); 10] = [
+			(&[1., 2., 3., 4.], 0.015, Ok(100.)),
+			(&[4., 3., 2., 1.], 0.015, Ok(-100.)),
+			(&[1., 2., 3., 4.], 0.03, Ok(50.)),
+			(&[2., 2., 2.], 0.015, Ok(0.)),
+			(&[], 0.015, Err(AnalysisError::SliceIsEmpty)),
+			(&[1., f64::NAN], 0.015, Err(AnalysisError::NonFiniteInput { index: 1 })),
+			(&[1., 2.], f64::NAN, Err(AnalysisError::InvalidConstant(f64::NAN))),
+			(&[1., 2.], f64::INFINITY, Err(AnalysisError::InvalidConstant(f64::INFINITY))),
+			(&[1., 2.], 0., Err(AnalysisError::InvalidConstant(0.))),
+			(&[1., 2.], -0.015, Err(AnalysisError::InvalidConstant(-0.015))),
+		];
+
+		for test in &tests {
+			let result = super::commodity_channel_index(test.0, test.1);
+			match (result, test.2.as_ref()) {
 				(Ok(val), Ok(exp))
-					=> assert_eq!(half_up(val, 6), *exp),
+					=> assert!((val - *exp).abs() < 1e-9),
 				(Err(err), Err(exp))
-					=> assert_eq!(err.description(), exp.description()),
+					=> assert_eq!(err.to_string(), exp.to_string()),
 				_ => panic!("return type mismatch"),
 			}
 		}
 	}
 
 	#[test]
-	fn williams_percent_r() {
-		let tests: [(f64, f64, f64, Result<f64>); 23] = [
-			(127.2876, 128.4317, 124.5615, Ok(-29.561780)),
-			(127.1781, 128.4317, 124.5615, Ok(-32.391091)),
-			(128.0138, 128.4317, 124.5615, Ok(-10.797891)),
-			(127.1085, 128.4317, 124.5615, Ok(-34.189447)),
-			(127.7253, 128.4317, 124.5615, Ok(-18.252287)),
-			(127.0587, 128.4317, 124.5615, Ok(-35.476203)),
-			(127.3273, 128.2725, 124.5615, Ok(-25.470224)),
-			(128.7103, 128.7700, 124.5615, Ok(-1.418558)),
-			(127.8745, 129.2873, 124.5615, Ok(-29.895467)),
-			(128.5809, 130.0633, 124.5615, Ok(-26.943909)),
-			(128.6008, 130.0633, 124.5615, Ok(-26.582209)),
-			(127.9342, 130.0633, 124.5715, Ok(-38.768710)),
-			(128.1133, 130.0633, 125.0689, Ok(-39.043729)),
-			(127.5960, 130.0633, 125.9245, Ok(-59.613898)),
-			(127.5960, 130.0633, 125.9245, Ok(-59.613898)),
-			(128.6904, 130.0633, 125.9245, Ok(-33.171451)),
-			(128.2725, 130.0633, 125.9245, Ok(-43.268580)),
+	fn cci() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 11] = [
+			-34.153363, -135.998540, -104.587156, 10.837542, 96.962765,
+			76.810748, 118.314677, 78.228990, 72.696286, 18.642997, 33.741867,
+		];
 
-			(100., 100., 100., Ok(-50.)),
-			(100., 100., 0., Ok(0.)),
-			(0., 100., 0., Ok(-100.)),
-			(100., 0., 100., Err(AnalysisError::HighLessThanLow)),
-			(100., 0., 0., Err(AnalysisError::CloseGreaterThanHigh)),
-			(0., 100., 100., Err(AnalysisError::CloseLessThanLow)),
+		let mut cci = super::Cci::new(10, 0.015).unwrap();
+		assert_eq!(cci.lookback(), 9);
+		assert_eq!(super::Cci::default().lookback(), 19);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = cci.next(*bar).unwrap();
+			assert_eq!(result, cci.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(cci.is_ready());
+					assert!((result.unwrap() - results[j]).abs() < 5e-7);
+
+					let typical: Vec<f64> = data[i - 9..=i].iter()
+						.map(|&(close, high, low)| (high + low + close) / 3.)
+						.collect();
+					let value = super::commodity_channel_index(&typical, 0.015).unwrap();
+					assert!((result.unwrap() - value).abs() < 1e-9);
+				},
+				None => {
+					assert!(!cci.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		cci.reset();
+		assert!(!cci.is_ready());
+		assert_eq!(cci.current(), None);
+
+		let series = super::cci_series(&bars, 10, 0.015).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+
+		let flat = to_bars(&[(100., 100., 100.); 3]);
+		let series = super::cci_series(&flat, 2, 0.015).unwrap();
+		assert_eq!(series, vec![None, Some(0.), Some(0.)]);
+
+		let tests: [(usize, usize, f64, AnalysisError); 5] = [
+			(9, 10, 0.015, AnalysisError::InsufficientData { needed: 10, got: 9 }),
+			(20, 0, 0.015, AnalysisError::InvalidPeriod(0)),
+			(20, 10, f64::NAN, AnalysisError::InvalidConstant(f64::NAN)),
+			(20, 10, 0., AnalysisError::InvalidConstant(0.)),
+			(20, 10, -1., AnalysisError::InvalidConstant(-1.)),
 		];
 
 		for test in &tests {
-			let result = super::williams_percent_r(test.0, test.1, test.2);
-			match (result, test.3.as_ref()) {
-				(Ok(val), Ok(exp))
-					=> assert_eq!(half_up(val, 6), *exp),
-				(Err(err), Err(exp))
-					=> assert_eq!(err.description(), exp.description()),
+			match super::cci_series(&bars[..test.0], test.1, test.2) {
+				Err(err) => assert_eq!(err.to_string(), test.3.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn dpo() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [f64; 18] = [
+			1.22367, 1.09825, 1.28279, 0.94696, 0.1017, 0.47458, 0.39684,
+			-0.23529, 0.4038, -0.30209, -0.68692, -2.04483, -1.91726,
+			-1.88743, -1.55528, -2.65213, -3.34503, -2.67603,
+		];
+
+		let mut dpo = super::Dpo::new(10).unwrap();
+		assert_eq!(dpo.lookback(), 15);
+		assert_eq!(super::Dpo::default().lookback(), 30);
+		for (i, close) in closes.iter().enumerate() {
+			let result = dpo.next(*close).unwrap();
+			assert_eq!(result, dpo.current());
+			match i.checked_sub(15) {
+				Some(j) => {
+					assert!(dpo.is_ready());
+					assert!((result.unwrap() - results[j]).abs() < 1e-9);
+					let value = super::detrended_price_oscillator(&closes[..=i], 10);
+					assert!((result.unwrap() - value.unwrap()).abs() < 1e-9);
+				},
+				None => {
+					assert!(!dpo.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		dpo.reset();
+		assert!(!dpo.is_ready());
+		assert_eq!(dpo.current(), None);
+
+		let series = super::dpo_series(&closes, 10).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..15], [None; 15]);
+		for (value, exp) in series[15..].iter().zip(results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 1e-9);
+		}
+
+		let tests: [(&[f64], usize, AnalysisError); 4] = [
+			(&closes[..15], 10, AnalysisError::InsufficientData { needed: 16, got: 15 }),
+			(&closes, 0, AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., 3., f64::NAN], 1, AnalysisError::NonFiniteInput { index: 3 }),
+			(&[], 1, AnalysisError::InsufficientData { needed: 2, got: 0 }),
+		];
+
+		for test in &tests {
+			match super::detrended_price_oscillator(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+		for test in &tests[..3] {
+			match super::dpo_series(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn stoch_rsi() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [(f64, f64); 20] = [
+			(28.927348, 34.535954), (45.655429, 31.752122), (50.160988, 41.581255),
+			(38.603714, 44.806710), (44.404694, 44.389799), (45.547361, 42.851923),
+			(37.626892, 42.526316), (25.523730, 36.232661), (40.928073, 34.692898),
+			(44.412785, 36.954863), (57.870614, 47.737157), (42.417038, 48.233479),
+			(38.932325, 46.406659), (5.598992, 28.982785), (3.643764, 16.058361),
+			(11.969171, 7.070642), (45.302504, 20.305146), (45.716402, 34.329359),
+			(37.390996, 42.803301), (24.885790, 35.997729),
+		];
+
+		let mut stoch_rsi = super::StochRsi::new(5, 5, 3, 3).unwrap();
+		assert_eq!(stoch_rsi.lookback(), 13);
+		assert_eq!(super::StochRsi::default().lookback(), 31);
+		for (i, close) in closes.iter().enumerate() {
+			let result = stoch_rsi.next(*close).unwrap();
+			assert_eq!(result, stoch_rsi.current());
+			match i.checked_sub(13) {
+				Some(j) => {
+					assert!(stoch_rsi.is_ready());
+					let (k, d) = result.unwrap();
+					assert!((k - results[j].0).abs() < 5e-7);
+					assert!((d - results[j].1).abs() < 5e-7);
+				},
+				None => {
+					assert!(!stoch_rsi.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		stoch_rsi.reset();
+		assert!(!stoch_rsi.is_ready());
+		assert_eq!(stoch_rsi.current(), None);
+
+		let series = super::stoch_rsi_series(&closes, 5, 5, 3, 3).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..13], [None; 13]);
+		for (value, exp) in series[13..].iter().zip(results.iter()) {
+			let (k, d) = value.unwrap();
+			assert!((k - exp.0).abs() < 5e-7);
+			assert!((d - exp.1).abs() < 5e-7);
+		}
+
+		let series = super::stoch_rsi_series(&[10., 11., 12., 13.], 1, 2, 1, 1).unwrap();
+		assert_eq!(series, vec![None, None, Some((50., 50.)), Some((50., 50.))]);
+
+		let tests: [(&[f64], [usize; 4], AnalysisError); 5] = [
+			(&closes[..13], [5, 5, 3, 3], AnalysisError::InsufficientData { needed: 14, got: 13 }),
+			(&closes, [0, 5, 3, 3], AnalysisError::InvalidPeriod(0)),
+			(&closes, [5, 0, 3, 3], AnalysisError::InvalidPeriod(0)),
+			(&closes, [5, 5, 3, 0], AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., 3., f64::NAN], [1, 1, 1, 1], AnalysisError::NonFiniteInput { index: 3 }),
+		];
+
+		for test in &tests {
+			let p = test.1;
+			match super::stoch_rsi_series(test.0, p[0], p[1], p[2], p[3]) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn connors_rsi() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [f64; 22] = [
+			53.231683, 25.523549, 73.576072, 44.265292, 27.797290, 53.762685,
+			78.829836, 36.618938, 13.895366, 73.263203, 66.787630, 23.292972,
+			75.645697, 23.688754, 26.593125, 8.457703, 56.327468, 59.145577,
+			72.184872, 17.318057, 16.238462, 64.233247,
+		];
+
+		let mut connors_rsi = super::ConnorsRsi::new(3, 2, 10).unwrap();
+		assert_eq!(connors_rsi.lookback(), 11);
+		assert_eq!(super::ConnorsRsi::default().lookback(), 101);
+		assert_eq!(super::ConnorsRsi::new(20, 2, 10).unwrap().lookback(), 20);
+		for (i, close) in closes.iter().enumerate() {
+			let result = connors_rsi.next(*close).unwrap();
+			assert_eq!(result, connors_rsi.current());
+			match i.checked_sub(11) {
+				Some(j) => {
+					assert!(connors_rsi.is_ready());
+					assert!((result.unwrap() - results[j]).abs() < 5e-7);
+				},
+				None => {
+					assert!(!connors_rsi.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		match connors_rsi.next(f64::NAN) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::NonFiniteInput { index: 0 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		connors_rsi.reset();
+		assert!(!connors_rsi.is_ready());
+		assert_eq!(connors_rsi.current(), None);
+
+		let series = super::connors_rsi_series(&closes, 3, 2, 10).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..11], [None; 11]);
+		for (value, exp) in series[11..].iter().zip(results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+
+		let tests: [(&[f64], [usize; 3], AnalysisError); 5] = [
+			(&closes[..11], [3, 2, 10], AnalysisError::InsufficientData { needed: 12, got: 11 }),
+			(&closes, [0, 2, 10], AnalysisError::InvalidPeriod(0)),
+			(&closes, [3, 0, 10], AnalysisError::InvalidPeriod(0)),
+			(&closes, [3, 2, 0], AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., 3., f64::NAN], [1, 1, 1], AnalysisError::NonFiniteInput { index: 3 }),
+		];
+
+		for test in &tests {
+			let p = test.1;
+			match super::connors_rsi_series(test.0, p[0], p[1], p[2]) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn tsi() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 11] = [
+			4.204562, -8.704446, -16.223137, 7.630522, 24.386828, 24.016924,
+			32.641644, 22.311407, 21.864985, -0.523880, 10.467529,
+		];
+
+		let mut tsi = super::Tsi::new(6, 4).unwrap();
+		assert_eq!(tsi.lookback(), 9);
+		assert_eq!(super::Tsi::default().lookback(), 37);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = tsi.next(*bar).unwrap();
+			assert_eq!(result, tsi.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(tsi.is_ready());
+					assert!((result.unwrap() - results[j]).abs() < 5e-7);
+				},
+				None => {
+					assert!(!tsi.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		tsi.reset();
+		assert!(!tsi.is_ready());
+		assert_eq!(tsi.current(), None);
+
+		let series = super::tsi_series(&bars, 6, 4).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+
+		let flat = to_bars(&[(100., 100., 100.); 4]);
+		let series = super::tsi_series(&flat, 2, 2).unwrap();
+		assert_eq!(series, vec![None, None, None, Some(0.)]);
+
+		let tests: [(usize, usize, usize, AnalysisError); 3] = [
+			(9, 6, 4, AnalysisError::InsufficientData { needed: 10, got: 9 }),
+			(20, 0, 4, AnalysisError::InvalidPeriod(0)),
+			(20, 6, 0, AnalysisError::InvalidPeriod(0)),
+		];
+
+		for test in &tests {
+			match super::tsi_series(&bars[..test.0], test.1, test.2) {
+				Err(err) => assert_eq!(err.to_string(), test.3.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn ultimate_oscillator() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 10] = [
+			44.351435, 31.767781, 54.849239, 58.525275, 59.505801, 56.927541,
+			45.935252, 45.778198, 24.585979, 44.464054,
+		];
+
+		let mut ultimate = super::UltimateOscillator::new(3, 5, 10).unwrap();
+		assert_eq!(ultimate.lookback(), 10);
+		assert_eq!(super::UltimateOscillator::default().lookback(), 28);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = ultimate.next(*bar).unwrap();
+			assert_eq!(result, ultimate.current());
+			match i.checked_sub(10) {
+				Some(j) => {
+					assert!(ultimate.is_ready());
+					assert!((result.unwrap() - results[j]).abs() < 5e-7);
+				},
+				None => {
+					assert!(!ultimate.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		ultimate.reset();
+		assert!(!ultimate.is_ready());
+		assert_eq!(ultimate.current(), None);
+
+		let series = super::ultimate_oscillator_series(&bars, 3, 5, 10).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+
+		let flat = to_bars(&[(100., 100., 100.); 3]);
+		let series = super::ultimate_oscillator_series(&flat, 1, 1, 2).unwrap();
+		assert_eq!(series, vec![None, None, Some(50.)]);
+
+		let tests: [(usize, [usize; 3], AnalysisError); 4] = [
+			(10, [3, 5, 10], AnalysisError::InsufficientData { needed: 11, got: 10 }),
+			(20, [0, 5, 10], AnalysisError::InvalidPeriod(0)),
+			(20, [3, 0, 10], AnalysisError::InvalidPeriod(0)),
+			(20, [3, 5, 0], AnalysisError::InvalidPeriod(0)),
+		];
+
+		for test in &tests {
+			let p = test.1;
+			match super::ultimate_oscillator_series(&bars[..test.0], p[0], p[1], p[2]) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn awesome_oscillator() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let awesome_results: [f64; 13] = [
+			-0.010417, -0.337917, -0.400625, -0.492083, -0.795208, -0.679375,
+			0.069583, 0.683750, 0.913125, 0.874167, 0.824167, 0.458958,
+			0.028333,
+		];
+		let accelerator_results: [f64; 11] = [
+			-0.150972, -0.081875, -0.232569, -0.023819, 0.537917, 0.659097,
+			0.357639, 0.050486, -0.046319, -0.260139, -0.408819,
+		];
+
+		let mut awesome = super::AwesomeOscillator::new(3, 8).unwrap();
+		let mut accelerator = super::AcceleratorOscillator::new(3, 8, 3).unwrap();
+		assert_eq!(awesome.lookback(), 7);
+		assert_eq!(accelerator.lookback(), 9);
+		assert_eq!(super::AwesomeOscillator::default().lookback(), 33);
+		assert_eq!(super::AcceleratorOscillator::default().lookback(), 37);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = awesome.next(*bar).unwrap();
+			assert_eq!(result, awesome.current());
+			match i.checked_sub(7) {
+				Some(j) => assert!((result.unwrap() - awesome_results[j]).abs() < 5e-7),
+				None => assert_eq!(result, None),
+			}
+
+			let result = accelerator.next(*bar).unwrap();
+			assert_eq!(result, accelerator.current());
+			assert_eq!(accelerator.is_ready(), i >= 9);
+			match i.checked_sub(9) {
+				Some(j) => assert!((result.unwrap() - accelerator_results[j]).abs() < 5e-7),
+				None => assert_eq!(result, None),
+			}
+		}
+
+		awesome.reset();
+		accelerator.reset();
+		assert!(!awesome.is_ready());
+		assert!(!accelerator.is_ready());
+		assert_eq!(accelerator.current(), None);
+
+		let series = super::awesome_oscillator_series(&bars, 3, 8).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..7], [None; 7]);
+		for (value, exp) in series[7..].iter().zip(awesome_results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+		let series = super::accelerator_oscillator_series(&bars, 3, 8, 3).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(accelerator_results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+
+		let tests: [(usize, [usize; 3], AnalysisError); 4] = [
+			(7, [3, 8, 1], AnalysisError::InsufficientData { needed: 8, got: 7 }),
+			(20, [0, 8, 1], AnalysisError::InvalidPeriod(0)),
+			(20, [3, 0, 1], AnalysisError::InvalidPeriod(0)),
+			(20, [3, 8, 0], AnalysisError::InvalidPeriod(0)),
+		];
+
+		for test in &tests {
+			let p = test.1;
+			match super::accelerator_oscillator_series(&bars[..test.0], p[0], p[1], p[2]) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+		for test in &tests[..3] {
+			let p = test.1;
+			match super::awesome_oscillator_series(&bars[..test.0], p[0], p[1]) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn kst() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [(f64, f64); 24] = [
+			(31.291348, 29.270386), (26.127272, 29.576237), (17.329392, 24.916004),
+			(6.797201, 16.751288), (4.299392, 9.475328), (6.720933, 5.939175),
+			(5.169262, 5.396529), (1.918182, 4.602793), (4.364088, 3.817178),
+			(3.792386, 3.358219), (-2.032430, 2.041348), (-4.101665, -0.780569),
+			(-0.236609, -2.123568), (-2.584022, -2.307432), (0.642476, -0.726052),
+			(0.123375, -0.606057), (-8.608495, -2.614215), (-26.011344, -11.498821),
+			(-38.165456, -24.261765), (-36.861823, -33.679541), (-22.792969, -32.606749),
+			(-21.270725, -26.975172), (-28.854411, -24.306035), (-31.023190, -27.049442),
+		];
+
+		let mut kst = super::Kst::new([2, 3, 4, 5], [2, 2, 2, 3], 3).unwrap();
+		assert_eq!(kst.lookback(), 9);
+		assert_eq!(super::Kst::default().lookback(), 52);
+		for (i, close) in closes.iter().enumerate() {
+			let result = kst.next(*close).unwrap();
+			assert_eq!(result, kst.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(kst.is_ready());
+					let (value, signal) = result.unwrap();
+					assert!((value - results[j].0).abs() < 5e-7);
+					assert!((signal - results[j].1).abs() < 5e-7);
+				},
+				None => {
+					assert!(!kst.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		match kst.next(f64::NAN) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::NonFiniteInput { index: 0 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		kst.reset();
+		assert!(!kst.is_ready());
+		assert_eq!(kst.current(), None);
+
+		let series = super::kst_series(&closes, [2, 3, 4, 5], [2, 2, 2, 3], 3).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			let (value, signal) = value.unwrap();
+			assert!((value - exp.0).abs() < 5e-7);
+			assert!((signal - exp.1).abs() < 5e-7);
+		}
+
+		let tests: [(usize, [[usize; 4]; 2], usize, AnalysisError); 4] = [
+			(9, [[2, 3, 4, 5], [2, 2, 2, 3]], 3, AnalysisError::InsufficientData { needed: 10, got: 9 }),
+			(33, [[2, 3, 0, 5], [2, 2, 2, 3]], 3, AnalysisError::InvalidPeriod(0)),
+			(33, [[2, 3, 4, 5], [2, 0, 2, 3]], 3, AnalysisError::InvalidPeriod(0)),
+			(33, [[2, 3, 4, 5], [2, 2, 2, 3]], 0, AnalysisError::InvalidPeriod(0)),
+		];
+
+		for test in &tests {
+			match super::kst_series(&closes[..test.0], test.1[0], test.1[1], test.2) {
+				Err(err) => assert_eq!(err.to_string(), test.3.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn coppock() {
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [f64; 25] = [
+			5.961352, 6.314562, 5.221686, 3.988007, 1.804680, 1.381327,
+			1.060175, 1.029680, 0.487293, 0.965598, 0.749123, -0.433228,
+			-0.384439, -0.185837, -0.595237, 0.111098, -0.194796, -1.200670,
+			-4.841192, -6.351479, -7.253684, -5.143005, -4.971528, -5.460169,
+			-5.561911,
+		];
+
+		let mut coppock = super::Coppock::new(5, 3, 4).unwrap();
+		assert_eq!(coppock.lookback(), 8);
+		assert_eq!(super::Coppock::default().lookback(), 23);
+		for (i, close) in closes.iter().enumerate() {
+			let result = coppock.next(*close).unwrap();
+			assert_eq!(result, coppock.current());
+			match i.checked_sub(8) {
+				Some(j) => {
+					assert!(coppock.is_ready());
+					assert!((result.unwrap() - results[j]).abs() < 5e-7);
+				},
+				None => {
+					assert!(!coppock.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		coppock.reset();
+		assert!(!coppock.is_ready());
+		assert_eq!(coppock.current(), None);
+
+		let series = super::coppock_series(&closes, 5, 3, 4).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..8], [None; 8]);
+		for (value, exp) in series[8..].iter().zip(results.iter()) {
+			assert!((value.unwrap() - *exp).abs() < 5e-7);
+		}
+
+		let tests: [(&[f64], [usize; 3], AnalysisError); 5] = [
+			(&closes[..8], [5, 3, 4], AnalysisError::InsufficientData { needed: 9, got: 8 }),
+			(&closes, [0, 3, 4], AnalysisError::InvalidPeriod(0)),
+			(&closes, [5, 0, 4], AnalysisError::InvalidPeriod(0)),
+			(&closes, [5, 3, 0], AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., f64::NAN], [1, 1, 1], AnalysisError::NonFiniteInput { index: 2 }),
+		];
+
+		for test in &tests {
+			let p = test.1;
+			match super::coppock_series(test.0, p[0], p[1], p[2]) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn elder_ray() {
+		let data: [(f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36), (126.50, 127.62, 126.16),
+			(125.17, 126.59, 124.93), (126.09, 127.35, 126.09),
+			(126.82, 128.17, 126.82), (126.78, 128.43, 126.48),
+			(126.39, 127.37, 126.03), (125.14, 126.42, 124.83),
+			(126.59, 126.90, 126.39), (125.87, 126.85, 125.72),
+			(125.04, 125.65, 124.56), (124.93, 125.72, 124.57),
+			(126.78, 127.16, 125.07), (127.42, 127.72, 126.86),
+			(126.94, 127.69, 126.63), (127.79, 128.22, 126.80),
+			(127.02, 128.27, 126.71), (127.40, 128.09, 126.80),
+			(126.13, 128.27, 126.13), (127.62, 127.74, 125.92),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, f64); 16] = [
+			(2.182000, 0.832000), (2.178000, 0.228000), (1.072000, -0.268000),
+			(0.508000, -1.082000), (0.762000, 0.252000), (0.801333, -0.328667),
+			(-0.062444, -1.152444), (0.268370, -0.881630), (1.265580, -0.824420),
+			(1.317053, 0.457053), (1.108036, 0.048036), (1.235357, -0.184643),
+			(1.273571, -0.286429), (0.959048, -0.330952), (1.472698, -0.667302),
+			(0.668466, -1.151534),
+		];
+
+		let mut elder_ray = super::ElderRay::new(5).unwrap();
+		assert_eq!(elder_ray.lookback(), 4);
+		assert_eq!(super::ElderRay::default().lookback(), 12);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = elder_ray.next(*bar).unwrap();
+			assert_eq!(result, elder_ray.current());
+			match i.checked_sub(4) {
+				Some(j) => {
+					assert!(elder_ray.is_ready());
+					let (bull, bear) = result.unwrap();
+					assert!((bull - results[j].0).abs() < 5e-7);
+					assert!((bear - results[j].1).abs() < 5e-7);
+				},
+				None => {
+					assert!(!elder_ray.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		elder_ray.reset();
+		assert!(!elder_ray.is_ready());
+		assert_eq!(elder_ray.current(), None);
+
+		let series = super::elder_ray_series(&bars, 5).unwrap();
+		assert_eq!(series.len(), bars.len());
+		assert_eq!(series[..4], [None; 4]);
+		for (value, exp) in series[4..].iter().zip(results.iter()) {
+			let (bull, bear) = value.unwrap();
+			assert!((bull - exp.0).abs() < 5e-7);
+			assert!((bear - exp.1).abs() < 5e-7);
+		}
+
+		let tests: [(usize, usize, AnalysisError); 2] = [
+			(4, 5, AnalysisError::InsufficientData { needed: 5, got: 4 }),
+			(20, 0, AnalysisError::InvalidPeriod(0)),
+		];
+
+		for test in &tests {
+			match super::elder_ray_series(&bars[..test.0], test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn elder_impulse() {
+		use super::Impulse::{Bearish, Bullish, Neutral};
+
+		let closes: [f64; 33] = [
+			44.3389, 44.0902, 44.1497, 43.6124, 44.3278, 44.8264, 45.0955,
+			45.4245, 45.8433, 46.0826, 45.8931, 46.0328, 45.6140, 46.2820,
+			46.2820, 46.0028, 46.0328, 46.4116, 46.2222, 45.6439, 46.2122,
+			46.2521, 45.7137, 46.4515, 45.7835, 45.3548, 44.0288, 44.1783,
+			44.2181, 44.5672, 43.4205, 42.6628, 43.1314,
+		];
+		let results: [super::Impulse; 25] = [
+			Bullish, Neutral, Neutral, Neutral, Bearish, Bullish, Bullish,
+			Bearish, Bullish, Bullish, Neutral, Bearish, Bullish, Bullish,
+			Bearish, Bullish, Bearish, Bearish, Bearish, Neutral, Neutral,
+			Neutral, Bearish, Bearish, Neutral,
+		];
+
+		let mut impulse = super::ElderImpulse::new(5, 3, 6, 3).unwrap();
+		assert_eq!(impulse.lookback(), 8);
+		assert_eq!(super::ElderImpulse::default().lookback(), 34);
+		for (i, close) in closes.iter().enumerate() {
+			let result = impulse.next(*close).unwrap();
+			assert_eq!(result, impulse.current());
+			match i.checked_sub(8) {
+				Some(j) => {
+					assert!(impulse.is_ready());
+					assert_eq!(result, Some(results[j]));
+				},
+				None => {
+					assert!(!impulse.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		impulse.reset();
+		assert!(!impulse.is_ready());
+		assert_eq!(impulse.current(), None);
+
+		let series = super::elder_impulse_series(&closes, 5, 3, 6, 3).unwrap();
+		assert_eq!(series.len(), closes.len());
+		assert_eq!(series[..8], [None; 8]);
+		for (value, exp) in series[8..].iter().zip(results.iter()) {
+			assert_eq!(*value, Some(*exp));
+		}
+
+		let series = super::elder_impulse_series(&[10.; 5], 1, 1, 2, 2).unwrap();
+		assert_eq!(series[3..], [Some(Neutral); 2]);
+
+		let tests: [(&[f64], [usize; 4], AnalysisError); 5] = [
+			(&closes[..8], [5, 3, 6, 3], AnalysisError::InsufficientData { needed: 9, got: 8 }),
+			(&closes, [0, 3, 6, 3], AnalysisError::InvalidPeriod(0)),
+			(&closes, [5, 0, 6, 3], AnalysisError::InvalidPeriod(0)),
+			(&closes, [5, 3, 6, 0], AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., 3., f64::NAN], [1, 1, 2, 2], AnalysisError::NonFiniteInput { index: 3 }),
+		];
+
+		for test in &tests {
+			let p = test.1;
+			match super::elder_impulse_series(test.0, p[0], p[1], p[2], p[3]) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
 				_ => panic!("return type mismatch"),
 			}
 		}
@@ -1,6 +1,8 @@
 //! Trend contains technical analysis indicators that try to predict the
 //! direction in which values are moving towards.
-use analysis::{AnalysisError, Result};
+use analysis::{self, AnalysisError, Bar, Indicator, Result};
+use analysis::momentum::Cmo;
+use analysis::window::{Extremum, Ring, Sum};
 
 /// Exponential moving average (EMA) is a filter that applies weighting factors
 /// which decrease exponentially. The weighting for each older datum decreases
@@ -10,10 +12,14 @@ use analysis::{AnalysisError, Result};
 /// of periods for short, medium and long term trends are 5-20, 20-60 and
 /// 100-200 respectively.
 ///
-/// Function calculates the weighting factor from the slice length and
-/// calculates the exponential moving average using that factor. If EMA is
-/// calculated for the first time previous argument needs to be 0. When previous
-/// is 0 function returns simple moving average of the datum points.
+/// Function calculates the weighting factor from the slice length and applies
+/// it to the last value of the slice and the previous EMA. If EMA is
+/// calculated for the first time `old` is `None` and the function returns the
+/// simple moving average of the datum points, which seeds the average.
+///
+/// The weighting factor and seed can be changed with `EmaConfig`. When values
+/// arrive one at a time `Ema` keeps the previous EMA and seeds itself during
+/// warm-up, so no slice has to be passed.
 ///
 /// # Arguments
 ///
@@ -30,148 +36,5576 @@ use analysis::{AnalysisError, Result};
 /// assert_eq!(value.ok(), Some(3.5));
 /// ```
 pub fn exponential_moving_average(slice: &[f64], old: Option<f64>) -> Result<f64> {
-	let length = slice.len();
-	if length == 0 {
-		return Err(AnalysisError::SliceIsEmpty);
+	EmaConfig::default().moving_average(slice, old)
+}
+
+/// Source of the EMA weighting factor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Smoothing {
+	/// `2 / (period + 1)`, the standard EMA weighting factor.
+	#[default]
+	Exponential,
+	/// `1 / period`, the smoothing used by J. Welles Wilder Jr. in RSI, ATR
+	/// and ADX.
+	Wilder,
+	/// Fixed weighting factor greater than 0 and less than or equal to 1.
+	Custom(f64),
+}
+
+impl Smoothing {
+	/// Returns the weighting factor for `period` values.
+	pub fn alpha(&self, period: usize) -> Result<f64> {
+		let alpha = match *self {
+			Smoothing::Exponential => 2. / (period as f64 + 1.),
+			Smoothing::Wilder => 1. / period as f64,
+			Smoothing::Custom(alpha) => alpha,
+		};
+		match alpha > 0. && alpha <= 1. {
+			true => Ok(alpha),
+			false => Err(AnalysisError::InvalidAlpha(alpha)),
+		}
 	}
-	Ok(match old {
-		Some(ema) => (slice[length-1] - ema) * 2. / (1. + length as f64) + ema,
-		None => try!(simple_moving_average(slice)),
-	})
 }
 
-/// Simple moving average (SMA) is the unweighted mean of the datum points.
+/// Initial value of the EMA.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Seed {
+	/// Simple moving average of the first `period` values.
+	#[default]
+	Sma,
+	/// The first value.
+	First,
+}
+
+/// Configuration of the exponential moving average.
 ///
-/// The number of periods depends on the analytical objectives. Typical number
-/// of periods for short, medium and long term trends are 5-20, 20-60 and
-/// 100-200 respectively.
+/// Platforms differ in how they calculate the EMA, so the weighting factor
+/// and the seed can be chosen to match their outputs. The default
+/// configuration uses the weighting factor `2 / (period + 1)` and seeds the
+/// average with the simple moving average, like `exponential_moving_average`
+/// and `Ema::new`.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::{EmaConfig, Seed, Smoothing};
+///
+/// let config = EmaConfig::new().smoothing(Smoothing::Wilder).seed(Seed::First);
+/// let mut ema = config.build(2).unwrap();
+/// assert_eq!(ema.next(4.).ok(), Some(Some(4.)));
+/// assert_eq!(ema.next(6.).ok(), Some(Some(5.)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EmaConfig {
+	smoothing: Smoothing,
+	seed: Seed,
+}
+
+impl EmaConfig {
+	/// Creates the default configuration.
+	pub fn new() -> EmaConfig {
+		EmaConfig::default()
+	}
+
+	/// Sets the source of the weighting factor.
+	pub fn smoothing(mut self, smoothing: Smoothing) -> EmaConfig {
+		self.smoothing = smoothing;
+		self
+	}
+
+	/// Sets the initial value of the average.
+	pub fn seed(mut self, seed: Seed) -> EmaConfig {
+		self.seed = seed;
+		self
+	}
+
+	/// Creates a new streaming exponential moving average over `period`
+	/// values using the configuration.
+	pub fn build(self, period: usize) -> Result<Ema> {
+		analysis::check_period(period)?;
+		Ok(Ema {
+			period,
+			alpha: self.smoothing.alpha(period)?,
+			seed: self.seed,
+			count: 0,
+			sum: 0.,
+			value: None,
+		})
+	}
+
+	/// Calculates the exponential moving average of the slice using the
+	/// configuration, with the weighting factor calculated from the slice
+	/// length.
+	///
+	/// When there is no previous EMA the average is seeded according to the
+	/// configuration. `Seed::Sma` returns the simple moving average of the
+	/// slice, `Seed::First` starts from the first value and applies the
+	/// weighting factor to each of the following values.
+	///
+	/// # Arguments
+	///
+	/// * `slice` - array of values
+	/// * `old` - previous EMA if any
+	///
+	/// # Example
+	///
+	/// ```
+	/// use stat::analysis::trend::{EmaConfig, Seed};
+	///
+	/// let config = EmaConfig::new().seed(Seed::First);
+	/// let value = config.moving_average(&[2., 5.], None);
+	/// assert_eq!(value.ok(), Some(4.));
+	/// ```
+	pub fn moving_average(&self, slice: &[f64], old: Option<f64>) -> Result<f64> {
+		let length = slice.len();
+		if length == 0 {
+			return Err(AnalysisError::SliceIsEmpty);
+		}
+		analysis::check_finite(slice)?;
+		let alpha = self.smoothing.alpha(length)?;
+		Ok(match (old, self.seed) {
+			(Some(ema), _) => smooth(slice[length-1], ema, alpha),
+			(None, Seed::Sma) => simple_moving_average(slice)?,
+			(None, Seed::First) => slice[1..].iter()
+				.fold(slice[0], |ema, value| smooth(*value, ema, alpha)),
+		})
+	}
+}
+
+/// Streaming exponential moving average.
+///
+/// `Ema` is the stateful counterpart of `exponential_moving_average`. Values
+/// are passed in one at a time and the previous average is kept internally,
+/// so there is no need to maintain a window of values. The weighting factor
+/// is calculated from the period the same way as the function calculates it
+/// from the slice length.
+///
+/// The average is seeded with the simple moving average of the first `period`
+/// values. Until then `next` returns `None`. Use `EmaConfig` to change the
+/// weighting factor or the seed.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Ema;
+///
+/// let mut ema = Ema::new(3).unwrap();
+/// assert_eq!(ema.next(3.).ok(), Some(None));
+/// assert_eq!(ema.next(4.).ok(), Some(None));
+/// assert_eq!(ema.next(5.).ok(), Some(Some(4.)));
+/// assert_eq!(ema.next(6.).ok(), Some(Some(5.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ema {
+	period: usize,
+	alpha: f64,
+	seed: Seed,
+	count: usize,
+	sum: f64,
+	value: Option<f64>,
+}
+
+impl Ema {
+	/// Creates a new exponential moving average over `period` values.
+	pub fn new(period: usize) -> Result<Ema> {
+		EmaConfig::default().build(period)
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Ema {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		self.value = match self.value {
+			Some(ema) => Some(smooth(value, ema, self.alpha)),
+			None if self.seed == Seed::First => Some(value),
+			None => {
+				self.count += 1;
+				self.sum += value;
+				match self.count == self.period {
+					true => Some(self.sum / self.period as f64),
+					false => None,
+				}
+			},
+		};
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.count = 0;
+		self.sum = 0.;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		match self.seed {
+			Seed::Sma => self.period - 1,
+			Seed::First => 0,
+		}
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the exponential moving average for every value of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions, before the average is seeded, are `None`.
 ///
 /// # Arguments
 ///
-/// * `slice` - array of values
+/// * `values` - array of values
+/// * `period` - number of periods
 ///
 /// # Example
 ///
 /// ```
 /// use stat::analysis::trend;
 ///
-/// let array = [3.5, 3.4, 3.3, 3.6, 3.7];
-/// let value = trend::simple_moving_average(&array);
-/// assert_eq!(value.ok(), Some(3.5));
+/// let values = trend::ema_series(&[3., 4., 5., 6.], 3);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(4.), Some(5.)]));
 /// ```
-pub fn simple_moving_average(slice: &[f64]) -> Result<f64> {
-	let length = slice.len();
-	if length == 0 {
-		return Err(AnalysisError::SliceIsEmpty);
+pub fn ema_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Ema::new(period)?, values)
+}
+
+/// Moving average convergence divergence (MACD) developed by Gerald Appel is
+/// the difference between a fast and a slow exponential moving average. The
+/// signal line is the EMA of MACD and the histogram is the difference between
+/// MACD and the signal line. Appel used 12 and 26 periods for the averages and
+/// 9 periods for the signal line, which `Macd` defaults to.
+///
+/// The percentage price oscillator (PPO) is the MACD variant that divides the
+/// difference by the slow EMA and multiplies it by 100, so that values of
+/// differently priced series can be compared. It is 0 while the slow EMA is
+/// 0.
+///
+/// The first value is available after `max(fast, slow) + signal - 1` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Macd;
+///
+/// let mut macd = Macd::new(1, 2, 2).unwrap();
+/// assert_eq!(macd.next(1.).ok(), Some(None));
+/// assert_eq!(macd.next(2.).ok(), Some(None));
+/// assert_eq!(macd.next(3.).ok(), Some(Some((0.5, 0.5, 0.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Macd {
+	fast: Ema,
+	slow: Ema,
+	signal: Ema,
+	percentage: bool,
+	value: Option<(f64, f64, f64)>,
+}
+
+impl Macd {
+	/// Creates a new moving average convergence divergence.
+	///
+	/// # Arguments
+	///
+	/// * `fast` - number of periods of the fast EMA
+	/// * `slow` - number of periods of the slow EMA
+	/// * `signal` - number of periods of the signal line
+	pub fn new(fast: usize, slow: usize, signal: usize) -> Result<Macd> {
+		Ok(Macd {
+			fast: Ema::new(fast)?,
+			slow: Ema::new(slow)?,
+			signal: Ema::new(signal)?,
+			percentage: false,
+			value: None,
+		})
+	}
+
+	/// Creates a new percentage price oscillator.
+	///
+	/// # Arguments
+	///
+	/// * `fast` - number of periods of the fast EMA
+	/// * `slow` - number of periods of the slow EMA
+	/// * `signal` - number of periods of the signal line
+	pub fn ppo(fast: usize, slow: usize, signal: usize) -> Result<Macd> {
+		let mut ppo = Macd::new(fast, slow, signal)?;
+		ppo.percentage = true;
+		Ok(ppo)
+	}
+
+	/// Returns the current MACD, signal line and histogram, or `None` during
+	/// warm-up.
+	pub fn current(&self) -> Option<(f64, f64, f64)> {
+		self.value
 	}
-	Ok(slice.iter().fold(0., |sum, x| sum + x) / length as f64)
 }
 
-#[cfg(test)]
-mod tests {
-	extern crate math;
-	use analysis::{AnalysisError, Result};
-	use self::math::round::half_to_even;
-	use std::error::Error;
+impl Default for Macd {
+	fn default() -> Macd {
+		Macd::new(12, 26, 9).unwrap()
+	}
+}
 
-	#[test]
-	fn exponential_moving_average() {
-		let values: [f64; 30] = [
-			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
-			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
-			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
-			23.10, 22.40, 22.17,
-		];
-		let results: [f64; 21] = [
-			22.221000, 22.208091, 22.241165, 22.266408, 22.328879, 22.516356,
-			22.795200, 22.968800, 23.125382, 23.275312, 23.339801, 23.427110,
-			23.507635, 23.533520, 23.471062, 23.403596, 23.390215, 23.261085,
-			23.231797, 23.080561, 22.915004,
-		];
-		let tests: [(&[f64], Option<f64>, Result<f64>); 22] = [
-			(&values[0..0], None, Err(AnalysisError::SliceIsEmpty)),
-			(&values[0..10], None, Ok(results[0])),
-			(&values[1..11], Some(results[0]), Ok(results[1])),
-			(&values[2..12], Some(results[1]), Ok(results[2])),
-			(&values[3..13], Some(results[2]), Ok(results[3])),
-			(&values[4..14], Some(results[3]), Ok(results[4])),
-			(&values[5..15], Some(results[4]), Ok(results[5])),
-			(&values[6..16], Some(results[5]), Ok(results[6])),
-			(&values[7..17], Some(results[6]), Ok(results[7])),
-			(&values[8..18], Some(results[7]), Ok(results[8])),
-			(&values[9..19], Some(results[8]), Ok(results[9])),
-			(&values[10..20], Some(results[9]), Ok(results[10])),
-			(&values[11..21], Some(results[10]), Ok(results[11])),
-			(&values[12..22], Some(results[11]), Ok(results[12])),
-			(&values[13..23], Some(results[12]), Ok(results[13])),
-			(&values[14..24], Some(results[13]), Ok(results[14])),
-			(&values[15..25], Some(results[14]), Ok(results[15])),
-			(&values[16..26], Some(results[15]), Ok(results[16])),
-			(&values[17..27], Some(results[16]), Ok(results[17])),
-			(&values[18..28], Some(results[17]), Ok(results[18])),
-			(&values[19..29], Some(results[18]), Ok(results[19])),
-			(&values[20..30], Some(results[19]), Ok(results[20])),
-		];
+impl Indicator for Macd {
+	type Input = f64;
+	type Output = (f64, f64, f64);
 
-		for test in &tests {
-			let result = super::exponential_moving_average(test.0, test.1);
-			match (result, test.2.as_ref()) {
-				(Ok(val), Ok(exp))
-					=> assert_eq!(half_to_even(val, 6), *exp),
-				(Err(err), Err(exp))
-					=> assert_eq!(err.description(), exp.description()),
-				_ => panic!("return type mismatch"),
-			}
+	fn next(&mut self, value: f64) -> Result<Option<(f64, f64, f64)>> {
+		let fast = self.fast.next(value)?;
+		let slow = self.slow.next(value)?;
+		let macd = match (fast, slow) {
+			(Some(fast), Some(slow)) if self.percentage => percentage(fast, slow),
+			(Some(fast), Some(slow)) => fast - slow,
+			_ => return Ok(None),
+		};
+		self.value = self.signal.next(macd)?
+			.map(|signal| (macd, signal, macd - signal));
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.fast.reset();
+		self.slow.reset();
+		self.signal.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.fast.lookback().max(self.slow.lookback()) + self.signal.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the moving average convergence divergence for every value of
+/// the series.
+///
+/// The result is aligned with the input so that the first
+/// `max(fast, slow) + signal - 2` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `fast` - number of periods of the fast EMA
+/// * `slow` - number of periods of the slow EMA
+/// * `signal` - number of periods of the signal line
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::macd_series(&[1., 2., 3.], 1, 2, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some((0.5, 0.5, 0.))]));
+/// ```
+pub fn macd_series(values: &[f64], fast: usize, slow: usize, signal: usize)
+	-> Result<Vec<Option<(f64, f64, f64)>>> {
+	analysis::series(Macd::new(fast, slow, signal)?, values)
+}
+
+/// Calculates the percentage price oscillator for every value of the series.
+///
+/// The result is aligned with the input so that the first
+/// `max(fast, slow) + signal - 2` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `fast` - number of periods of the fast EMA
+/// * `slow` - number of periods of the slow EMA
+/// * `signal` - number of periods of the signal line
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::ppo_series(&[1., 3., 3.], 1, 2, 2).unwrap();
+/// let (ppo, signal, _) = values[2].unwrap();
+/// assert!((ppo - 12.5).abs() < 1e-9);
+/// assert!((signal - 31.25).abs() < 1e-9);
+/// ```
+pub fn ppo_series(values: &[f64], fast: usize, slow: usize, signal: usize)
+	-> Result<Vec<Option<(f64, f64, f64)>>> {
+	analysis::series(Macd::ppo(fast, slow, signal)?, values)
+}
+
+/// Double exponential moving average (DEMA) developed by Patrick Mulloy
+/// reduces the lag of the exponential moving average by subtracting the
+/// EMA of the EMA from twice the EMA: `2 * EMA - EMA(EMA)`.
+///
+/// Both stages use the same period and are seeded the same way as `Ema`, so
+/// the first value is available after `2 * (period - 1) + 1` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Dema;
+///
+/// let mut dema = Dema::new(2).unwrap();
+/// assert_eq!(dema.next(1.).ok(), Some(None));
+/// assert_eq!(dema.next(2.).ok(), Some(None));
+/// assert_eq!(dema.next(3.).ok(), Some(Some(3.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dema {
+	stages: [Ema; 2],
+}
+
+impl Dema {
+	/// Creates a new double exponential moving average over `period` values.
+	pub fn new(period: usize) -> Result<Dema> {
+		Ok(Dema {
+			stages: [Ema::new(period)?, Ema::new(period)?],
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		match (self.stages[0].current(), self.stages[1].current()) {
+			(Some(e1), Some(e2)) => Some(2. * e1 - e2),
+			_ => None,
 		}
 	}
+}
 
-	#[test]
-	fn simple_moving_average() {
-		let values: [f64; 30] = [
-			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
-			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
-			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
-			23.10, 22.40, 22.17,
-		];
-		let results: [f64; 21] = [
-			22.221, 22.209, 22.229, 22.259, 22.303, 22.421, 22.613, 22.765,
-			22.905, 23.076, 23.210, 23.377, 23.525, 23.652, 23.710, 23.684,
-			23.612, 23.505, 23.432, 23.277, 23.131,
-		];
-		let tests: [(&[f64], Result<f64>); 22] = [
-			(&values[0..0], Err(AnalysisError::SliceIsEmpty)),
-			(&values[0..10], Ok(results[0])),
-			(&values[1..11], Ok(results[1])),
-			(&values[2..12], Ok(results[2])),
-			(&values[3..13], Ok(results[3])),
-			(&values[4..14], Ok(results[4])),
-			(&values[5..15], Ok(results[5])),
-			(&values[6..16], Ok(results[6])),
-			(&values[7..17], Ok(results[7])),
-			(&values[8..18], Ok(results[8])),
-			(&values[9..19], Ok(results[9])),
-			(&values[10..20], Ok(results[10])),
-			(&values[11..21], Ok(results[11])),
-			(&values[12..22], Ok(results[12])),
-			(&values[13..23], Ok(results[13])),
-			(&values[14..24], Ok(results[14])),
-			(&values[15..25], Ok(results[15])),
-			(&values[16..26], Ok(results[16])),
-			(&values[17..27], Ok(results[17])),
-			(&values[18..28], Ok(results[18])),
-			(&values[19..29], Ok(results[19])),
-			(&values[20..30], Ok(results[20])),
-		];
+impl Indicator for Dema {
+	type Input = f64;
+	type Output = f64;
 
-		for test in &tests {
-			let result = super::simple_moving_average(test.0);
-			match (result, test.1.as_ref()) {
-				(Ok(val), Ok(exp))
-					=> assert_eq!(half_to_even(val, 3), *exp),
-				(Err(err), Err(exp))
-					=> assert_eq!(err.description(), exp.description()),
-				_ => panic!("return type mismatch"),
-			}
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		cascade(&mut self.stages, value)?;
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		for ema in &mut self.stages {
+			ema.reset();
+		}
+	}
+
+	fn lookback(&self) -> usize {
+		self.stages.iter().map(Ema::lookback).sum()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.stages[1].is_ready()
+	}
+}
+
+/// Calculates the double exponential moving average for every value of the
+/// series.
+///
+/// The result is aligned with the input so that the first
+/// `2 * (period - 1)` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::dema_series(&[1., 2., 3.], 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(3.)]));
+/// ```
+pub fn dema_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Dema::new(period)?, values)
+}
+
+/// Triple exponential moving average (TEMA) developed by Patrick Mulloy
+/// reduces the lag further than `Dema` by combining three chained EMA stages:
+/// `3 * EMA - 3 * EMA(EMA) + EMA(EMA(EMA))`.
+///
+/// The first value is available after `3 * (period - 1) + 1` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Tema;
+///
+/// let mut tema = Tema::new(2).unwrap();
+/// for value in &[1., 2., 3.] {
+///     assert_eq!(tema.next(*value).ok(), Some(None));
+/// }
+/// assert_eq!(tema.next(4.).ok(), Some(Some(4.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tema {
+	stages: [Ema; 3],
+}
+
+impl Tema {
+	/// Creates a new triple exponential moving average over `period` values.
+	pub fn new(period: usize) -> Result<Tema> {
+		Ok(Tema {
+			stages: [Ema::new(period)?, Ema::new(period)?, Ema::new(period)?],
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		let stages = &self.stages;
+		match (stages[0].current(), stages[1].current(), stages[2].current()) {
+			(Some(e1), Some(e2), Some(e3)) => Some(3. * e1 - 3. * e2 + e3),
+			_ => None,
+		}
+	}
+}
+
+impl Indicator for Tema {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		cascade(&mut self.stages, value)?;
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		for ema in &mut self.stages {
+			ema.reset();
+		}
+	}
+
+	fn lookback(&self) -> usize {
+		self.stages.iter().map(Ema::lookback).sum()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.stages[2].is_ready()
+	}
+}
+
+/// Calculates the triple exponential moving average for every value of the
+/// series.
+///
+/// The result is aligned with the input so that the first
+/// `3 * (period - 1)` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::tema_series(&[1., 2., 3., 4.], 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, None, Some(4.)]));
+/// ```
+pub fn tema_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Tema::new(period)?, values)
+}
+
+/// Tillson T3 moving average developed by Tim Tillson is a smooth, low-lag
+/// average built from six chained EMA stages. The stages are combined using
+/// coefficients derived from the volume factor, which controls how much the
+/// average reacts to recent changes. Tillson suggested the volume factor 0.7.
+///
+/// The first value is available after `6 * (period - 1) + 1` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::T3;
+///
+/// let mut t3 = T3::new(2, 0.7).unwrap();
+/// for value in &[1., 2., 3., 4., 5., 6.] {
+///     assert_eq!(t3.next(*value).ok(), Some(None));
+/// }
+/// assert!(t3.next(7.).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct T3 {
+	stages: [Ema; 6],
+	coefficients: [f64; 4],
+}
+
+impl T3 {
+	/// Creates a new T3 moving average.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of periods of each EMA stage
+	/// * `volume_factor` - volume factor, typically between 0 and 1
+	pub fn new(period: usize, volume_factor: f64) -> Result<T3> {
+		analysis::check_finite(&[volume_factor])
+			.map_err(|err| analysis::locate(err, 1))?;
+		let v = volume_factor;
+		Ok(T3 {
+			stages: [
+				Ema::new(period)?, Ema::new(period)?, Ema::new(period)?,
+				Ema::new(period)?, Ema::new(period)?, Ema::new(period)?,
+			],
+			coefficients: [
+				-v * v * v,
+				3. * v * v + 3. * v * v * v,
+				-6. * v * v - 3. * v - 3. * v * v * v,
+				1. + 3. * v + v * v * v + 3. * v * v,
+			],
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		let stages = &self.stages;
+		let c = &self.coefficients;
+		match (stages[2].current(), stages[3].current(),
+			stages[4].current(), stages[5].current()) {
+			(Some(e3), Some(e4), Some(e5), Some(e6))
+				=> Some(c[0] * e6 + c[1] * e5 + c[2] * e4 + c[3] * e3),
+			_ => None,
+		}
+	}
+}
+
+impl Indicator for T3 {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		cascade(&mut self.stages, value)?;
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		for ema in &mut self.stages {
+			ema.reset();
+		}
+	}
+
+	fn lookback(&self) -> usize {
+		self.stages.iter().map(Ema::lookback).sum()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.stages[5].is_ready()
+	}
+}
+
+/// Calculates the T3 moving average for every value of the series.
+///
+/// The result is aligned with the input so that the first
+/// `6 * (period - 1)` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods of each EMA stage
+/// * `volume_factor` - volume factor, typically between 0 and 1
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::t3_series(&[1.; 7], 2, 0.7).unwrap();
+/// assert_eq!(values[5], None);
+/// assert!((values[6].unwrap() - 1.).abs() < 1e-9);
+/// ```
+pub fn t3_series(values: &[f64], period: usize, volume_factor: f64)
+	-> Result<Vec<Option<f64>>> {
+	analysis::series(T3::new(period, volume_factor)?, values)
+}
+
+/// Zero lag exponential moving average (ZLEMA) developed by John Ehlers and
+/// Ric Way removes part of the EMA lag by feeding it de-lagged values
+/// `2 * value - value[lag]`, where `lag` is `(period - 1) / 2`.
+///
+/// The first value is available after `lag + period` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Zlema;
+///
+/// let mut zlema = Zlema::new(3).unwrap();
+/// for value in &[1., 2., 3.] {
+///     assert_eq!(zlema.next(*value).ok(), Some(None));
+/// }
+/// assert_eq!(zlema.next(4.).ok(), Some(Some(4.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Zlema {
+	window: Ring<f64>,
+	ema: Ema,
+}
+
+impl Zlema {
+	/// Creates a new zero lag exponential moving average over `period` values.
+	pub fn new(period: usize) -> Result<Zlema> {
+		analysis::check_period(period)?;
+		Ok(Zlema {
+			window: Ring::new((period - 1) / 2 + 1),
+			ema: Ema::new(period)?,
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.ema.current()
+	}
+}
+
+impl Indicator for Zlema {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		self.window.push(value);
+		match self.window.get(self.window.capacity() - 1) {
+			Some(lagged) => self.ema.next(2. * value - lagged),
+			None => Ok(None),
+		}
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.ema.reset();
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity() - 1 + self.ema.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.ema.is_ready()
+	}
+}
+
+/// Calculates the zero lag exponential moving average for every value of the
+/// series.
+///
+/// The result is aligned with the input so that the first
+/// `(period - 1) / 2 + period - 1` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::zlema_series(&[1., 2., 3., 4.], 3);
+/// assert_eq!(values.ok(), Some(vec![None, None, None, Some(4.)]));
+/// ```
+pub fn zlema_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Zlema::new(period)?, values)
+}
+
+/// TRIX developed by Jack Hutson is the 1-period rate of change of a triple
+/// smoothed exponential moving average, expressed as a percentage. The triple
+/// smoothing filters out cycles shorter than the period. An optional signal
+/// line, the EMA of TRIX, can be used to generate crossover signals.
+///
+/// The formula is modified to return 0 if the previous triple smoothed EMA
+/// is 0.
+///
+/// The first value is available after `3 * (period - 1) + 2` values. The
+/// signal line is returned by `signal` once it is seeded.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Trix;
+///
+/// let mut trix = Trix::new(1, Some(2)).unwrap();
+/// assert_eq!(trix.next(10.).ok(), Some(None));
+/// assert_eq!(trix.next(11.).ok(), Some(Some(10.)));
+/// assert_eq!(trix.signal(), None);
+/// assert_eq!(trix.next(11.).ok(), Some(Some(0.)));
+/// assert_eq!(trix.signal(), Some(5.));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trix {
+	stages: [Ema; 3],
+	signal: Option<Ema>,
+	previous: Option<f64>,
+	value: Option<f64>,
+}
+
+impl Trix {
+	/// Creates a new TRIX.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of periods of each EMA stage
+	/// * `signal` - number of periods of the signal line, if any
+	pub fn new(period: usize, signal: Option<usize>) -> Result<Trix> {
+		Ok(Trix {
+			stages: [Ema::new(period)?, Ema::new(period)?, Ema::new(period)?],
+			signal: match signal {
+				Some(signal) => Some(Ema::new(signal)?),
+				None => None,
+			},
+			previous: None,
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+
+	/// Returns the current signal line, or `None` if it was not requested or
+	/// is still warming up.
+	pub fn signal(&self) -> Option<f64> {
+		self.signal.as_ref().and_then(Ema::current)
+	}
+}
+
+impl Indicator for Trix {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		let ema = match cascade(&mut self.stages, value)? {
+			Some(ema) => ema,
+			None => return Ok(None),
+		};
+		if let Some(previous) = self.previous.replace(ema) {
+			let trix = percentage(ema, previous);
+			if let Some(ref mut signal) = self.signal {
+				signal.next(trix)?;
+			}
+			self.value = Some(trix);
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		for ema in &mut self.stages {
+			ema.reset();
+		}
+		if let Some(ref mut signal) = self.signal {
+			signal.reset();
+		}
+		self.previous = None;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.stages.iter().map(Ema::lookback).sum::<usize>() + 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates TRIX for every value of the series.
+///
+/// The result is aligned with the input so that the first
+/// `3 * (period - 1) + 1` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods of each EMA stage
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::trix_series(&[10., 11., 11.], 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some(10.), Some(0.)]));
+/// ```
+pub fn trix_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Trix::new(period, None)?, values)
+}
+
+/// Calculates TRIX and its signal line for every value of the series.
+///
+/// The result is aligned with the input so that the first
+/// `3 * (period - 1) + signal` positions, before the signal line is seeded,
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods of each EMA stage
+/// * `signal` - number of periods of the signal line
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::trix_signal_series(&[10., 11., 11.], 1, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some((0., 5.))]));
+/// ```
+pub fn trix_signal_series(values: &[f64], period: usize, signal: usize)
+	-> Result<Vec<Option<(f64, f64)>>> {
+	let mut trix = Trix::new(period, Some(signal))?;
+	let needed = trix.lookback() + signal;
+	if values.len() < needed {
+		return Err(AnalysisError::InsufficientData { needed, got: values.len() });
+	}
+	values.iter()
+		.enumerate()
+		.map(|(i, value)| {
+			let value = trix.next(*value).map_err(|err| analysis::locate(err, i))?;
+			Ok(value.and_then(|value| trix.signal().map(|signal| (value, signal))))
+		})
+		.collect()
+}
+
+/// Passes the value through the chained EMA stages and returns the output of
+/// the last stage, or `None` if any stage is still warming up.
+fn cascade(stages: &mut [Ema], value: f64) -> Result<Option<f64>> {
+	let mut value = value;
+	for ema in stages.iter_mut() {
+		value = match ema.next(value)? {
+			Some(value) => value,
+			None => return Ok(None),
+		};
+	}
+	Ok(Some(value))
+}
+
+/// Returns the difference between `value` and `base` as a percentage of
+/// `base`, or 0 if `base` is 0.
+fn percentage(value: f64, base: f64) -> f64 {
+	match base {
+		0. => 0.,
+		_ => 100. * (value - base) / base,
+	}
+}
+
+/// Kaufman adaptive moving average (KAMA) developed by Perry Kaufman adjusts
+/// its smoothing constant to the efficiency ratio of the market. The
+/// efficiency ratio is the net change over `period` values divided by the sum
+/// of the absolute changes between consecutive values. When the values move
+/// in one direction the ratio approaches 1 and the average follows them
+/// closely, when the values are noisy the ratio approaches 0 and the average
+/// barely moves.
+///
+/// The smoothing constant is `(ER * (fast - slow) + slow)^2`, where `fast` and
+/// `slow` are the EMA weighting factors of the fast and slow periods. Kaufman
+/// suggested 10 periods for the efficiency ratio and 2 and 30 periods for the
+/// fast and slow constants. If the values did not change at all the
+/// efficiency ratio is 1.
+///
+/// The average is seeded with the value preceding the first efficiency ratio,
+/// so the first value is available after `period + 1` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Kama;
+///
+/// let mut kama = Kama::new(2, 1, 1).unwrap();
+/// assert_eq!(kama.next(1.).ok(), Some(None));
+/// assert_eq!(kama.next(2.).ok(), Some(None));
+/// assert_eq!(kama.next(3.).ok(), Some(Some(3.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kama {
+	window: Ring<f64>,
+	changes: Ring<f64>,
+	volatility: Sum,
+	fast: f64,
+	slow: f64,
+	value: Option<f64>,
+}
+
+impl Kama {
+	/// Creates a new Kaufman adaptive moving average.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of periods of the efficiency ratio
+	/// * `fast_period` - number of periods of the fastest smoothing constant
+	/// * `slow_period` - number of periods of the slowest smoothing constant
+	pub fn new(period: usize, fast_period: usize, slow_period: usize) -> Result<Kama> {
+		analysis::check_period(period)?;
+		analysis::check_period(fast_period)?;
+		analysis::check_period(slow_period)?;
+		Ok(Kama {
+			window: Ring::new(period + 1),
+			changes: Ring::new(period),
+			volatility: Sum::default(),
+			fast: 2. / (fast_period as f64 + 1.),
+			slow: 2. / (slow_period as f64 + 1.),
+			value: None,
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Kama {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		self.window.push(value);
+		let previous = match self.window.get(1) {
+			Some(previous) => previous,
+			None => return Ok(None),
+		};
+		if let Some(old) = self.changes.push((value - previous).abs()) {
+			self.volatility.add(-old);
+		}
+		self.volatility.add((value - previous).abs());
+		if !self.window.is_full() {
+			return Ok(None);
+		}
+
+		let direction = (value - self.window.get(self.changes.capacity()).unwrap()).abs();
+		let volatility = self.volatility.value();
+		let ratio = match volatility > 0. {
+			true => direction / volatility,
+			false => 1.,
+		};
+		let alpha = (ratio * (self.fast - self.slow) + self.slow).powi(2);
+		self.value = Some(smooth(value, self.value.unwrap_or(previous), alpha));
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.changes.clear();
+		self.volatility.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.changes.capacity()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the Kaufman adaptive moving average for every value of the
+/// series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods of the efficiency ratio
+/// * `fast_period` - number of periods of the fastest smoothing constant
+/// * `slow_period` - number of periods of the slowest smoothing constant
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::kama_series(&[1., 2., 3.], 2, 1, 1);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(3.)]));
+/// ```
+pub fn kama_series(values: &[f64], period: usize, fast_period: usize, slow_period: usize)
+	-> Result<Vec<Option<f64>>> {
+	analysis::series(Kama::new(period, fast_period, slow_period)?, values)
+}
+
+/// Variable index dynamic average (VIDYA) developed by Tushar Chande scales
+/// the EMA weighting factor by the absolute value of the Chande momentum
+/// oscillator (CMO). The CMO is the difference between the sums of gains and
+/// losses over `cmo_period` changes divided by their total, so the average
+/// speeds up in strong trends and slows down in sideways markets. If the
+/// values did not change at all the CMO is 0 and the average does not move.
+///
+/// The average is seeded with the value preceding the first CMO, so the first
+/// value is available after `cmo_period + 1` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Vidya;
+///
+/// let mut vidya = Vidya::new(1, 2).unwrap();
+/// assert_eq!(vidya.next(1.).ok(), Some(None));
+/// assert_eq!(vidya.next(2.).ok(), Some(None));
+/// assert_eq!(vidya.next(3.).ok(), Some(Some(3.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Vidya {
+	alpha: f64,
+	cmo: Cmo,
+	previous: Option<f64>,
+	value: Option<f64>,
+}
+
+impl Vidya {
+	/// Creates a new variable index dynamic average.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of periods of the EMA weighting factor
+	/// * `cmo_period` - number of periods of the Chande momentum oscillator
+	pub fn new(period: usize, cmo_period: usize) -> Result<Vidya> {
+		analysis::check_period(period)?;
+		analysis::check_period(cmo_period)?;
+		Ok(Vidya {
+			alpha: 2. / (period as f64 + 1.),
+			cmo: Cmo::new(cmo_period)?,
+			previous: None,
+			value: None,
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Indicator for Vidya {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		let previous = self.previous.replace(value);
+		let (cmo, previous) = match (self.cmo.next(value)?, previous) {
+			(Some(cmo), Some(previous)) => (cmo, previous),
+			_ => return Ok(None),
+		};
+		let alpha = self.alpha * cmo.abs() / 100.;
+		self.value = Some(smooth(value, self.value.unwrap_or(previous), alpha));
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.cmo.reset();
+		self.previous = None;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.cmo.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the variable index dynamic average for every value of the
+/// series.
+///
+/// The result is aligned with the input so that the first `cmo_period`
+/// positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods of the EMA weighting factor
+/// * `cmo_period` - number of periods of the Chande momentum oscillator
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::vidya_series(&[1., 2., 3.], 1, 2);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(3.)]));
+/// ```
+pub fn vidya_series(values: &[f64], period: usize, cmo_period: usize)
+	-> Result<Vec<Option<f64>>> {
+	analysis::series(Vidya::new(period, cmo_period)?, values)
+}
+
+/// MESA adaptive moving average (MAMA) and its following adaptive moving
+/// average (FAMA) developed by John Ehlers. The phase of the values is
+/// measured with a Hilbert transform discriminator and the weighting factor is
+/// the fast limit divided by the rate of change of the phase, bounded below by
+/// the slow limit. FAMA applies half of that factor to MAMA, so crossings of
+/// the two lines signal trend changes. Ehlers suggested the limits 0.5 and
+/// 0.05 and the median price `(high + low) / 2` as input.
+///
+/// Both averages are seeded with the first value. The Hilbert transform needs
+/// a long history to settle, so the first output is available after 33
+/// values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Mama;
+///
+/// let mut mama = Mama::default();
+/// for _ in 0..32 {
+///     assert_eq!(mama.next(1.).ok(), Some(None));
+/// }
+/// assert_eq!(mama.next(1.).ok(), Some(Some((1., 1.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mama {
+	fast_limit: f64,
+	slow_limit: f64,
+	count: usize,
+	prices: Ring<f64>,
+	smoothed: Ring<f64>,
+	detrender: Ring<f64>,
+	in_phase: Ring<f64>,
+	quadrature: Ring<f64>,
+	i2: f64,
+	q2: f64,
+	re: f64,
+	im: f64,
+	period: f64,
+	phase: f64,
+	mama: f64,
+	fama: f64,
+}
+
+impl Mama {
+	/// Creates a new MESA adaptive moving average.
+	///
+	/// # Arguments
+	///
+	/// * `fast_limit` - upper bound of the weighting factor, at most 1
+	/// * `slow_limit` - lower bound of the weighting factor, greater than 0
+	///   and at most `fast_limit`
+	pub fn new(fast_limit: f64, slow_limit: f64) -> Result<Mama> {
+		analysis::check_finite(&[fast_limit, slow_limit])?;
+		analysis::check_alpha(fast_limit)?;
+		analysis::check_alpha(slow_limit)?;
+		if slow_limit > fast_limit {
+			return Err(AnalysisError::InvalidAlpha(slow_limit));
+		}
+		Ok(Mama {
+			fast_limit,
+			slow_limit,
+			count: 0,
+			prices: Ring::new(4),
+			smoothed: Ring::new(7),
+			detrender: Ring::new(7),
+			in_phase: Ring::new(7),
+			quadrature: Ring::new(7),
+			i2: 0.,
+			q2: 0.,
+			re: 0.,
+			im: 0.,
+			period: 0.,
+			phase: 0.,
+			mama: 0.,
+			fama: 0.,
+		})
+	}
+
+	/// Returns the current MAMA and FAMA, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, f64)> {
+		match self.is_ready() {
+			true => Some((self.mama, self.fama)),
+			false => None,
+		}
+	}
+
+	fn update(&mut self, value: f64) {
+		let price = |age| self.prices.get(age).unwrap_or(0.);
+		let smoothed = (4. * price(0) + 3. * price(1) + 2. * price(2) + price(3)) / 10.;
+		let adjustment = 0.075 * self.period + 0.54;
+		self.smoothed.push(smoothed);
+		let detrender = hilbert(&self.smoothed, adjustment);
+		self.detrender.push(detrender);
+
+		let i1 = self.detrender.get(3).unwrap_or(0.);
+		let q1 = hilbert(&self.detrender, adjustment);
+		self.in_phase.push(i1);
+		self.quadrature.push(q1);
+		let ji = hilbert(&self.in_phase, adjustment);
+		let jq = hilbert(&self.quadrature, adjustment);
+
+		let i2 = 0.2 * (i1 - jq) + 0.8 * self.i2;
+		let q2 = 0.2 * (q1 + ji) + 0.8 * self.q2;
+		let re = 0.2 * (i2 * self.i2 + q2 * self.q2) + 0.8 * self.re;
+		let im = 0.2 * (i2 * self.q2 - q2 * self.i2) + 0.8 * self.im;
+		let mut period = self.period;
+		if re != 0. && im != 0. {
+			period = 360. / (im / re).atan().to_degrees();
+		}
+		period = period.min(1.5 * self.period).max(0.67 * self.period).clamp(6., 50.);
+		self.period = 0.2 * period + 0.8 * self.period;
+		self.i2 = i2;
+		self.q2 = q2;
+		self.re = re;
+		self.im = im;
+
+		let mut phase = self.phase;
+		if i1 != 0. {
+			phase = (q1 / i1).atan().to_degrees();
+		}
+		let delta = (self.phase - phase).max(1.);
+		self.phase = phase;
+		let alpha = (self.fast_limit / delta).max(self.slow_limit);
+		self.mama = smooth(value, self.mama, alpha);
+		self.fama = smooth(self.mama, self.fama, alpha / 2.);
+	}
+}
+
+impl Default for Mama {
+	fn default() -> Mama {
+		Mama::new(0.5, 0.05).unwrap()
+	}
+}
+
+impl Indicator for Mama {
+	type Input = f64;
+	type Output = (f64, f64);
+
+	fn next(&mut self, value: f64) -> Result<Option<(f64, f64)>> {
+		analysis::check_finite(&[value])?;
+		self.count += 1;
+		self.prices.push(value);
+		match self.prices.is_full() {
+			true => self.update(value),
+			false => {
+				self.mama = value;
+				self.fama = value;
+			},
+		}
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		*self = Mama::new(self.fast_limit, self.slow_limit).unwrap();
+	}
+
+	fn lookback(&self) -> usize {
+		32
+	}
+
+	fn is_ready(&self) -> bool {
+		self.count > self.lookback()
+	}
+}
+
+/// Calculates the MESA adaptive moving average and its following adaptive
+/// moving average for every value of the series.
+///
+/// The result is aligned with the input so that the first 32 positions are
+/// `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `fast_limit` - upper bound of the weighting factor
+/// * `slow_limit` - lower bound of the weighting factor
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::mama_series(&[1.; 33], 0.5, 0.05).unwrap();
+/// assert_eq!(values[31], None);
+/// assert_eq!(values[32], Some((1., 1.)));
+/// ```
+pub fn mama_series(values: &[f64], fast_limit: f64, slow_limit: f64)
+	-> Result<Vec<Option<(f64, f64)>>> {
+	analysis::series(Mama::new(fast_limit, slow_limit)?, values)
+}
+
+/// Applies Ehlers' Hilbert transform to the last seven values of the buffer.
+/// Values missing during warm-up are treated as 0.
+fn hilbert(values: &Ring<f64>, adjustment: f64) -> f64 {
+	let value = |age| values.get(age).unwrap_or(0.);
+	(0.0962 * value(0) + 0.5769 * value(2) - 0.5769 * value(4) - 0.0962 * value(6))
+		* adjustment
+}
+
+/// Moves the previous average towards the value by the weighting factor
+/// `alpha`. This is the recurrence shared by all exponential averages.
+fn smooth(value: f64, old: f64, alpha: f64) -> f64 {
+	(value - old) * alpha + old
+}
+
+/// Simple moving average (SMA) is the unweighted mean of the datum points.
+///
+/// The number of periods depends on the analytical objectives. Typical number
+/// of periods for short, medium and long term trends are 5-20, 20-60 and
+/// 100-200 respectively.
+///
+/// # Arguments
+///
+/// * `slice` - array of values
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let array = [3.5, 3.4, 3.3, 3.6, 3.7];
+/// let value = trend::simple_moving_average(&array);
+/// assert_eq!(value.ok(), Some(3.5));
+/// ```
+pub fn simple_moving_average(slice: &[f64]) -> Result<f64> {
+	let length = slice.len();
+	if length == 0 {
+		return Err(AnalysisError::SliceIsEmpty);
+	}
+	analysis::check_finite(slice)?;
+	Ok(slice.iter().fold(0., |sum, x| sum + x) / length as f64)
+}
+
+/// Streaming simple moving average.
+///
+/// `Sma` is the stateful counterpart of `simple_moving_average`. The last
+/// `period` values are kept in a ring buffer together with their running sum,
+/// so each update is O(1) regardless of the period. The running sum uses
+/// Kahan summation to keep rounding errors from accumulating over long series.
+///
+/// Until `period` values have been seen `next` returns `None`.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Sma;
+///
+/// let mut sma = Sma::new(3).unwrap();
+/// assert_eq!(sma.next(3.).ok(), Some(None));
+/// assert_eq!(sma.next(4.).ok(), Some(None));
+/// assert_eq!(sma.next(5.).ok(), Some(Some(4.)));
+/// assert_eq!(sma.next(6.).ok(), Some(Some(5.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sma {
+	window: Ring<f64>,
+	sum: Sum,
+}
+
+impl Sma {
+	/// Creates a new simple moving average over `period` values.
+	pub fn new(period: usize) -> Result<Sma> {
+		analysis::check_period(period)?;
+		Ok(Sma {
+			window: Ring::new(period),
+			sum: Sum::default(),
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		match self.is_ready() {
+			true => Some(self.sum.value() / self.window.capacity() as f64),
+			false => None,
+		}
+	}
+}
+
+impl Indicator for Sma {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		if let Some(old) = self.window.push(value) {
+			self.sum.add(-old);
+		}
+		self.sum.add(value);
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.sum.reset();
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity() - 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.window.is_full()
+	}
+}
+
+/// Calculates the simple moving average for every value of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions, before the window is full, are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::sma_series(&[3., 4., 5., 6.], 3);
+/// assert_eq!(values.ok(), Some(vec![None, None, Some(4.), Some(5.)]));
+/// ```
+pub fn sma_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Sma::new(period)?, values)
+}
+
+/// Weighted moving average (WMA) is the linearly weighted mean of the datum
+/// points. The latest value has the weight equal to the number of periods and
+/// the weight decreases by one for each older value.
+///
+/// WMA reacts to recent changes faster than the simple moving average with the
+/// same number of periods.
+///
+/// # Arguments
+///
+/// * `slice` - array of values
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let array = [3., 4., 5., 6.];
+/// let value = trend::weighted_moving_average(&array);
+/// assert_eq!(value.ok(), Some(5.));
+/// ```
+pub fn weighted_moving_average(slice: &[f64]) -> Result<f64> {
+	let length = slice.len();
+	if length == 0 {
+		return Err(AnalysisError::SliceIsEmpty);
+	}
+	analysis::check_finite(slice)?;
+	let weighted = slice.iter()
+		.enumerate()
+		.fold(0., |sum, (i, x)| sum + (i + 1) as f64 * x);
+	Ok(weighted / (length * (length + 1) / 2) as f64)
+}
+
+/// Streaming weighted moving average.
+///
+/// `Wma` is the stateful counterpart of `weighted_moving_average`. The running
+/// sum and the running weighted sum of the window are updated on each value,
+/// so each update is O(1) regardless of the period.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Wma;
+///
+/// let mut wma = Wma::new(2).unwrap();
+/// assert_eq!(wma.next(3.).ok(), Some(None));
+/// assert_eq!(wma.next(6.).ok(), Some(Some(5.)));
+/// assert_eq!(wma.next(3.).ok(), Some(Some(4.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Wma {
+	window: Ring<f64>,
+	sum: Sum,
+	weighted: Sum,
+}
+
+impl Wma {
+	/// Creates a new weighted moving average over `period` values.
+	pub fn new(period: usize) -> Result<Wma> {
+		analysis::check_period(period)?;
+		Ok(Wma {
+			window: Ring::new(period),
+			sum: Sum::default(),
+			weighted: Sum::default(),
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		let period = self.window.capacity();
+		match self.is_ready() {
+			true => Some(self.weighted.value() / (period * (period + 1) / 2) as f64),
+			false => None,
+		}
+	}
+}
+
+impl Indicator for Wma {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		analysis::check_finite(&[value])?;
+		match self.window.push(value) {
+			Some(old) => {
+				// Every older value loses one weight and the oldest drops out.
+				let period = self.window.capacity() as f64;
+				self.weighted.add(period * value - self.sum.value());
+				self.sum.add(value - old);
+			},
+			None => {
+				self.weighted.add(self.window.len() as f64 * value);
+				self.sum.add(value);
+			},
+		}
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.sum.reset();
+		self.weighted.reset();
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity() - 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.window.is_full()
+	}
+}
+
+/// Calculates the weighted moving average for every value of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions, before the window is full, are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::wma_series(&[3., 6., 3.], 2);
+/// assert_eq!(values.ok(), Some(vec![None, Some(5.), Some(4.)]));
+/// ```
+pub fn wma_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Wma::new(period)?, values)
+}
+
+/// Hull moving average (HMA) developed by Alan Hull is a weighted moving
+/// average that reduces lag while keeping the curve smooth. It is calculated
+/// as the weighted moving average over `sqrt(period)` periods of
+/// `2 * WMA(period / 2) - WMA(period)`.
+///
+/// The average is calculated for the last value of the slice, which must
+/// contain at least `period + sqrt(period) - 1` values.
+///
+/// # Arguments
+///
+/// * `slice` - array of values
+/// * `period` - number of periods, at least 2
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let array = [1., 2., 3., 4., 5.];
+/// let value = trend::hull_moving_average(&array, 4);
+/// assert_eq!(value.ok(), Some(5.));
+/// ```
+pub fn hull_moving_average(slice: &[f64], period: usize) -> Result<f64> {
+	if slice.is_empty() {
+		return Err(AnalysisError::SliceIsEmpty);
+	}
+	let mut hma = Hma::new(period)?;
+	for (i, value) in slice.iter().enumerate() {
+		hma.next(*value).map_err(|err| analysis::locate(err, i))?;
+	}
+	hma.current().ok_or(AnalysisError::InsufficientData {
+		needed: hma.lookback() + 1,
+		got: slice.len(),
+	})
+}
+
+/// Streaming Hull moving average.
+///
+/// `Hma` chains the weighted moving averages used by `hull_moving_average`.
+/// The first value is available after `period + sqrt(period) - 1` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::Hma;
+///
+/// let mut hma = Hma::new(4).unwrap();
+/// for value in &[1., 2., 3., 4.] {
+///     assert_eq!(hma.next(*value).ok(), Some(None));
+/// }
+/// assert_eq!(hma.next(5.).ok(), Some(Some(5.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Hma {
+	half: Wma,
+	full: Wma,
+	hull: Wma,
+}
+
+impl Hma {
+	/// Creates a new Hull moving average over `period` values.
+	///
+	/// The period must be at least 2 so that the half period is not zero.
+	pub fn new(period: usize) -> Result<Hma> {
+		if period < 2 {
+			return Err(AnalysisError::InvalidPeriod(period));
+		}
+		Ok(Hma {
+			half: Wma::new(period / 2)?,
+			full: Wma::new(period)?,
+			hull: Wma::new((period as f64).sqrt() as usize)?,
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.hull.current()
+	}
+}
+
+impl Indicator for Hma {
+	type Input = f64;
+	type Output = f64;
+
+	fn next(&mut self, value: f64) -> Result<Option<f64>> {
+		let half = self.half.next(value)?;
+		let full = self.full.next(value)?;
+		match (half, full) {
+			(Some(half), Some(full)) => self.hull.next(2. * half - full),
+			_ => Ok(None),
+		}
+	}
+
+	fn reset(&mut self) {
+		self.half.reset();
+		self.full.reset();
+		self.hull.reset();
+	}
+
+	fn lookback(&self) -> usize {
+		self.full.lookback() + self.hull.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.hull.is_ready()
+	}
+}
+
+/// Calculates the Hull moving average for every value of the series.
+///
+/// The result is aligned with the input so that the first
+/// `period + sqrt(period) - 2` positions are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods, at least 2
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::hma_series(&[1., 2., 3., 4., 5.], 4);
+/// assert_eq!(values.ok(), Some(vec![None, None, None, None, Some(5.)]));
+/// ```
+pub fn hma_series(values: &[f64], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Hma::new(period)?, values)
+}
+
+/// Volume weighted moving average (VWMA) is the mean of the closing prices
+/// weighted by the traded volume of each bar.
+///
+/// The formula is modified to return the simple moving average of the closing
+/// prices if the total volume is 0.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{trend, Bar};
+///
+/// let bars = [
+///     Bar::new(10., 10., 10., 10., 100., 0).unwrap(),
+///     Bar::new(20., 20., 20., 20., 300., 1).unwrap(),
+/// ];
+/// let value = trend::volume_weighted_moving_average(&bars);
+/// assert_eq!(value.ok(), Some(17.5));
+/// ```
+pub fn volume_weighted_moving_average(bars: &[Bar]) -> Result<f64> {
+	let length = bars.len();
+	if length == 0 {
+		return Err(AnalysisError::SliceIsEmpty);
+	}
+	let (price, price_volume, volume) = bars.iter()
+		.fold((0., 0., 0.), |(p, pv, v), bar| {
+			(p + bar.close(), pv + bar.close() * bar.volume(), v + bar.volume())
+		});
+	Ok(match volume == 0. {
+		true => price / length as f64,
+		false => price_volume / volume,
+	})
+}
+
+/// Streaming volume weighted moving average.
+///
+/// `Vwma` is the stateful counterpart of `volume_weighted_moving_average`
+/// keeping running sums over a window of `period` bars.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::Vwma;
+///
+/// let mut vwma = Vwma::new(2).unwrap();
+/// let bar = |close, volume| Bar::new(close, close, close, close, volume, 0).unwrap();
+/// assert_eq!(vwma.next(bar(10., 100.)).ok(), Some(None));
+/// assert_eq!(vwma.next(bar(20., 300.)).ok(), Some(Some(17.5)));
+/// assert_eq!(vwma.next(bar(30., 0.)).ok(), Some(Some(20.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Vwma {
+	window: Ring<(f64, f64)>,
+	price: Sum,
+	price_volume: Sum,
+	volume: Sum,
+	traded: usize,
+}
+
+impl Vwma {
+	/// Creates a new volume weighted moving average over `period` bars.
+	pub fn new(period: usize) -> Result<Vwma> {
+		analysis::check_period(period)?;
+		Ok(Vwma {
+			window: Ring::new(period),
+			price: Sum::default(),
+			price_volume: Sum::default(),
+			volume: Sum::default(),
+			traded: 0,
+		})
+	}
+
+	/// Returns the current average, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		if !self.is_ready() {
+			return None;
+		}
+		Some(match self.traded == 0 {
+			true => self.price.value() / self.window.capacity() as f64,
+			false => self.price_volume.value() / self.volume.value(),
+		})
+	}
+}
+
+impl Indicator for Vwma {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		let (close, volume) = (bar.close(), bar.volume());
+		if let Some((close, volume)) = self.window.push((close, volume)) {
+			self.price.add(-close);
+			self.price_volume.add(-close * volume);
+			self.volume.add(-volume);
+			self.traded -= (volume != 0.) as usize;
+		}
+		self.price.add(close);
+		self.price_volume.add(close * volume);
+		self.volume.add(volume);
+		self.traded += (volume != 0.) as usize;
+		// The sums keep rounding residue after the last traded bar leaves the
+		// window, so they are cleared rather than trusted to reach 0.
+		if self.traded == 0 {
+			self.price_volume.reset();
+			self.volume.reset();
+		}
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.price.reset();
+		self.price_volume.reset();
+		self.volume.reset();
+		self.traded = 0;
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity() - 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.window.is_full()
+	}
+}
+
+/// Calculates the volume weighted moving average for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions, before the window is full, are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{trend, Bar};
+///
+/// let bar = |close, volume| Bar::new(close, close, close, close, volume, 0).unwrap();
+/// let values = trend::vwma_series(&[bar(10., 100.), bar(20., 300.)], 2);
+/// assert_eq!(values.ok(), Some(vec![None, Some(17.5)]));
+/// ```
+pub fn vwma_series(bars: &[Bar], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Vwma::new(period)?, bars)
+}
+
+/// Least squares line fitted to a window of values.
+///
+/// The positions of the values are the x coordinates, the oldest value is
+/// at 0 and the latest value at `length - 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regression {
+	length: usize,
+	slope: f64,
+	intercept: f64,
+	r_squared: f64,
+	standard_error: f64,
+}
+
+impl Regression {
+	/// Builds the regression from the mean of the values and the centered
+	/// sums `sxy`, of the products of the position and value deviations, and
+	/// `syy`, of the squared value deviations.
+	fn fit(length: usize, mean: f64, sxy: f64, syy: f64) -> Regression {
+		let n = length as f64;
+		let sxx = n * (n * n - 1.) / 12.;
+		let syy = syy.max(0.);
+		let slope = sxy / sxx;
+		let errors = (syy - slope * sxy).max(0.);
+		Regression {
+			length,
+			slope,
+			intercept: mean - slope * (n - 1.) / 2.,
+			r_squared: match syy > 0. {
+				true => 1. - errors / syy,
+				false => 1.,
+			},
+			standard_error: match n > 2. {
+				true => (errors / (n - 2.)).sqrt(),
+				false => 0.,
+			},
+		}
+	}
+
+	/// Returns the slope of the line.
+	pub fn slope(&self) -> f64 {
+		self.slope
+	}
+
+	/// Returns the value of the line at the oldest value.
+	pub fn intercept(&self) -> f64 {
+		self.intercept
+	}
+
+	/// Returns the coefficient of determination. It is 1 when all values are
+	/// equal, since the line fits them perfectly.
+	pub fn r_squared(&self) -> f64 {
+		self.r_squared
+	}
+
+	/// Returns the standard error of the estimate, the standard deviation of
+	/// the residuals with `length - 2` degrees of freedom. It is 0 for two
+	/// values.
+	pub fn standard_error(&self) -> f64 {
+		self.standard_error
+	}
+
+	/// Returns the value of the line at the latest value, also known as the
+	/// least squares moving average (LSMA).
+	pub fn value(&self) -> f64 {
+		self.intercept + self.slope * (self.length - 1) as f64
+	}
+
+	/// Returns the value of the line one position after the latest value,
+	/// also known as the time series forecast (TSF).
+	pub fn forecast(&self) -> f64 {
+		self.intercept + self.slope * self.length as f64
+	}
+
+	/// Returns the linear regression channel as `(upper, middle, lower)`. The
+	/// middle line is `value` and the upper and lower lines are `deviations`
+	/// standard errors away from it.
+	pub fn channel(&self, deviations: f64) -> (f64, f64, f64) {
+		let value = self.value();
+		let width = deviations * self.standard_error;
+		(value + width, value, value - width)
+	}
+}
+
+/// Fits a least squares line to the values.
+///
+/// # Arguments
+///
+/// * `slice` - array of values, at least 2
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let fit = trend::linear_regression(&[1., 3., 5.]).unwrap();
+/// assert_eq!(fit.slope(), 2.);
+/// assert_eq!(fit.value(), 5.);
+/// assert_eq!(fit.forecast(), 7.);
+/// ```
+pub fn linear_regression(slice: &[f64]) -> Result<Regression> {
+	match slice.len() {
+		0 => return Err(AnalysisError::SliceIsEmpty),
+		1 => return Err(AnalysisError::InsufficientData { needed: 2, got: 1 }),
+		_ => (),
+	}
+	analysis::check_finite(slice)?;
+	let n = slice.len() as f64;
+	let mean = slice.iter().sum::<f64>() / n;
+	let (mut sxy, mut syy) = (0., 0.);
+	for (position, value) in slice.iter().enumerate() {
+		let deviation = value - mean;
+		sxy += (position as f64 - (n - 1.) / 2.) * deviation;
+		syy += deviation * deviation;
+	}
+	Ok(Regression::fit(slice.len(), mean, sxy, syy))
+}
+
+/// Streaming linear regression over the last `period` values.
+///
+/// `LinearRegression` keeps running sums of the values, their squares and
+/// the values weighted by their positions. When the window moves each
+/// remaining value moves one position back, which lowers the weighted sum by
+/// the sum of the remaining values, so each update is O(1) regardless of the
+/// period. The sums use Kahan summation.
+///
+/// The sums are taken over the distances of the values from an anchor
+/// rather than over the values themselves, otherwise the squares of large
+/// prices would cancel out the variance of the window. Every `period` values
+/// the anchor moves to the mean of the window and the sums are recomputed,
+/// which keeps the distances small and amortizes to O(1) per update.
+///
+/// The output is the `Regression` of the window. The first value is
+/// available after `period` values.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::Indicator;
+/// use stat::analysis::trend::LinearRegression;
+///
+/// let mut regression = LinearRegression::new(3).unwrap();
+/// assert_eq!(regression.next(1.).ok(), Some(None));
+/// assert_eq!(regression.next(2.).ok(), Some(None));
+/// assert_eq!(regression.next(3.).unwrap().map(|fit| fit.value()), Some(3.));
+/// assert_eq!(regression.next(2.).unwrap().map(|fit| fit.slope()), Some(0.));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearRegression {
+	window: Ring<f64>,
+	anchor: f64,
+	pushed: usize,
+	sum: Sum,
+	squares: Sum,
+	weighted: Sum,
+}
+
+impl LinearRegression {
+	/// Creates a new linear regression over `period` values.
+	///
+	/// The period must be at least 2 so that the slope is defined.
+	pub fn new(period: usize) -> Result<LinearRegression> {
+		if period < 2 {
+			return Err(AnalysisError::InvalidPeriod(period));
+		}
+		Ok(LinearRegression {
+			window: Ring::new(period),
+			anchor: 0.,
+			pushed: 0,
+			sum: Sum::default(),
+			squares: Sum::default(),
+			weighted: Sum::default(),
+		})
+	}
+
+	/// Returns the current regression, or `None` during warm-up.
+	pub fn current(&self) -> Option<Regression> {
+		if !self.window.is_full() {
+			return None;
+		}
+		let n = self.window.capacity() as f64;
+		let (sum, squares) = (self.sum.value(), self.squares.value());
+		Some(Regression::fit(
+			self.window.capacity(),
+			self.anchor + sum / n,
+			self.weighted.value() - (n - 1.) / 2. * sum,
+			squares - sum * sum / n,
+		))
+	}
+
+	/// Moves the anchor to the mean of the window and recomputes the sums
+	/// from the window, discarding the rounding accumulated by the updates.
+	fn rebase(&mut self) {
+		let window = &self.window;
+		let values = (0..window.len()).rev().map(|age| window.get(age).unwrap());
+		let anchor = values.clone().sum::<f64>() / window.len() as f64;
+		self.anchor = anchor;
+		self.sum.reset();
+		self.squares.reset();
+		self.weighted.reset();
+		for (position, value) in values.enumerate() {
+			let distance = value - anchor;
+			self.sum.add(distance);
+			self.squares.add(distance * distance);
+			self.weighted.add(position as f64 * distance);
+		}
+		self.pushed = 0;
+	}
+}
+
+impl Indicator for LinearRegression {
+	type Input = f64;
+	type Output = Regression;
+
+	fn next(&mut self, value: f64) -> Result<Option<Regression>> {
+		analysis::check_finite(&[value])?;
+		if self.window.len() == 0 {
+			self.anchor = value;
+		}
+		let distance = value - self.anchor;
+		match self.window.push(value) {
+			Some(old) => {
+				let old = old - self.anchor;
+				self.weighted.add(old - self.sum.value());
+				self.weighted.add((self.window.capacity() - 1) as f64 * distance);
+				self.sum.add(-old);
+				self.squares.add(-old * old);
+			},
+			None => self.weighted.add((self.window.len() - 1) as f64 * distance),
+		}
+		self.sum.add(distance);
+		self.squares.add(distance * distance);
+		self.pushed += 1;
+		if self.pushed >= self.window.capacity() {
+			self.rebase();
+		}
+		Ok(self.current())
+	}
+
+	fn reset(&mut self) {
+		self.window.clear();
+		self.anchor = 0.;
+		self.pushed = 0;
+		self.sum.reset();
+		self.squares.reset();
+		self.weighted.reset();
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity() - 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.window.is_full()
+	}
+}
+
+/// Calculates the linear regression for every value of the series.
+///
+/// The result is aligned with the input so that the first `period - 1`
+/// positions, before the window is full, are `None`.
+///
+/// # Arguments
+///
+/// * `values` - array of values
+/// * `period` - number of periods
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let values = trend::linear_regression_series(&[1., 2., 4.], 2).unwrap();
+/// let slopes: Vec<_> = values.iter().map(|fit| fit.map(|fit| fit.slope())).collect();
+/// assert_eq!(slopes, vec![None, Some(1.), Some(2.)]);
+/// ```
+pub fn linear_regression_series(values: &[f64], period: usize)
+	-> Result<Vec<Option<Regression>>> {
+	analysis::series(LinearRegression::new(period)?, values)
+}
+
+/// Directional movement developed by J. Welles Wilder Jr. compares the range
+/// of the current bar with the range of the previous bar. The plus directional
+/// movement (+DM) is the increase of the high and the minus directional
+/// movement (-DM) is the decrease of the low. Only the larger one is counted,
+/// the other one is 0, and both are 0 if neither moved outward.
+///
+/// # Arguments
+///
+/// * `high` - high price of the current bar
+/// * `low` - low price of the current bar
+/// * `previous_high` - high price of the previous bar
+/// * `previous_low` - low price of the previous bar
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::trend;
+///
+/// let value = trend::directional_movement(12., 9., 10., 8.);
+/// assert_eq!(value.ok(), Some((2., 0.)));
+/// ```
+pub fn directional_movement(high: f64, low: f64, previous_high: f64, previous_low: f64)
+	-> Result<(f64, f64)> {
+	analysis::check_finite(&[high, low, previous_high, previous_low])?;
+	if high < low || previous_high < previous_low {
+		return Err(AnalysisError::HighLessThanLow);
+	}
+	let up = high - previous_high;
+	let down = previous_low - low;
+	Ok(match (up > down && up > 0., down > up && down > 0.) {
+		(true, _) => (up, 0.),
+		(_, true) => (0., down),
+		_ => (0., 0.),
+	})
+}
+
+/// Streaming directional movement index (DMI) calculated from bar data.
+///
+/// The true range, +DM and -DM of each bar are smoothed using Wilder's method.
+/// The first smoothed values are the sums of the first `period` values, after
+/// which each value is updated as `previous - previous / period + current`.
+/// The plus and minus directional indicators (+DI and -DI) are the smoothed
+/// +DM and -DM as a percentage of the smoothed true range, and the
+/// directional index (DX) is `100 * |+DI - -DI| / (+DI + -DI)`. DX measures
+/// the strength of the trend regardless of its direction.
+///
+/// The output is `(+DI, -DI, DX)`. The first value is available after
+/// `period + 1` bars. `Dmi` defaults to the 14 periods suggested by Wilder.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::Dmi;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut dmi = Dmi::new(1).unwrap();
+/// assert_eq!(dmi.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(dmi.next(bar(11., 12., 8.)).ok(), Some(Some((50., 0., 100.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dmi {
+	period: usize,
+	count: usize,
+	previous: Option<Bar>,
+	range: f64,
+	plus: f64,
+	minus: f64,
+	value: Option<(f64, f64, f64)>,
+}
+
+impl Dmi {
+	/// Creates a new directional movement index over `period` bars.
+	pub fn new(period: usize) -> Result<Dmi> {
+		analysis::check_period(period)?;
+		Ok(Dmi {
+			period,
+			count: 0,
+			previous: None,
+			range: 0.,
+			plus: 0.,
+			minus: 0.,
+			value: None,
+		})
+	}
+
+	/// Returns the current `(+DI, -DI, DX)`, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, f64, f64)> {
+		self.value
+	}
+}
+
+impl Default for Dmi {
+	fn default() -> Dmi {
+		Dmi::new(14).unwrap()
+	}
+}
+
+impl Indicator for Dmi {
+	type Input = Bar;
+	type Output = (f64, f64, f64);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, f64, f64)>> {
+		let previous = match self.previous.replace(bar) {
+			Some(previous) => previous,
+			None => return Ok(None),
+		};
+		let (plus, minus) = directional_movement(bar.high(), bar.low(),
+			previous.high(), previous.low())?;
+		let range = true_range(&bar, previous.close());
+
+		let (period, warming) = (self.period as f64, self.count < self.period);
+		let wilder = |sum: f64, value| match warming {
+			true => sum + value,
+			false => sum - sum / period + value,
+		};
+		self.range = wilder(self.range, range);
+		self.plus = wilder(self.plus, plus);
+		self.minus = wilder(self.minus, minus);
+		self.count += 1;
+		if self.count < self.period {
+			return Ok(None);
+		}
+
+		let (plus, minus) = match self.range > 0. {
+			true => (100. * self.plus / self.range, 100. * self.minus / self.range),
+			false => (0., 0.),
+		};
+		let dx = match plus + minus > 0. {
+			true => 100. * (plus - minus).abs() / (plus + minus),
+			false => 0.,
+		};
+		self.value = Some((plus, minus, dx));
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.count = 0;
+		self.previous = None;
+		self.range = 0.;
+		self.plus = 0.;
+		self.minus = 0.;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.period
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the directional movement index for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(11., 12., 8., 11., 0., 1).unwrap(),
+/// ];
+/// let values = trend::dmi_series(&bars, 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some((50., 0., 100.))]));
+/// ```
+pub fn dmi_series(bars: &[Bar], period: usize) -> Result<Vec<Option<(f64, f64, f64)>>> {
+	analysis::series(Dmi::new(period)?, bars)
+}
+
+/// Streaming average directional index (ADX) calculated from bar data.
+///
+/// ADX is the directional index (DX) of `Dmi` smoothed using Wilder's method.
+/// The first value is the simple average of the first `period` DX values,
+/// after which it is updated as `(previous * (period - 1) + DX) / period`.
+/// Wilder considered values above 25 to indicate a trending market.
+///
+/// The first value is available after `2 * period` bars. `Adx` defaults to
+/// 14 periods.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::Adx;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut adx = Adx::new(1).unwrap();
+/// assert_eq!(adx.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(adx.next(bar(11., 12., 8.)).ok(), Some(Some(100.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adx {
+	dmi: Dmi,
+	count: usize,
+	sum: f64,
+	value: Option<f64>,
+}
+
+impl Adx {
+	/// Creates a new average directional index over `period` bars.
+	pub fn new(period: usize) -> Result<Adx> {
+		Ok(Adx {
+			dmi: Dmi::new(period)?,
+			count: 0,
+			sum: 0.,
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+
+	/// Returns the current `(+DI, -DI, DX)` of the underlying `Dmi`, or
+	/// `None` during warm-up.
+	pub fn dmi(&self) -> Option<(f64, f64, f64)> {
+		self.dmi.current()
+	}
+}
+
+impl Default for Adx {
+	fn default() -> Adx {
+		Adx::new(14).unwrap()
+	}
+}
+
+impl Indicator for Adx {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		let dx = match self.dmi.next(bar)? {
+			Some((_, _, dx)) => dx,
+			None => return Ok(None),
+		};
+		let period = self.dmi.period as f64;
+		self.value = match self.value {
+			Some(adx) => Some((adx * (period - 1.) + dx) / period),
+			None => {
+				self.count += 1;
+				self.sum += dx;
+				match self.count == self.dmi.period {
+					true => Some(self.sum / period),
+					false => None,
+				}
+			},
+		};
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.dmi.reset();
+		self.count = 0;
+		self.sum = 0.;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		2 * self.dmi.period - 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the average directional index for every bar of the series.
+///
+/// The result is aligned with the input so that the first `2 * period - 1`
+/// positions are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(11., 12., 8., 11., 0., 1).unwrap(),
+/// ];
+/// let values = trend::adx_series(&bars, 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some(100.)]));
+/// ```
+pub fn adx_series(bars: &[Bar], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Adx::new(period)?, bars)
+}
+
+/// Streaming average directional movement index rating (ADXR) calculated
+/// from bar data.
+///
+/// ADXR is the average of the current ADX and the ADX `period - 1` bars ago.
+/// It lags ADX and is used to rate the directional movement of markets
+/// against each other.
+///
+/// The first value is available after `3 * period - 1` bars. `Adxr`
+/// defaults to 14 periods.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::Adxr;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut adxr = Adxr::new(1).unwrap();
+/// assert_eq!(adxr.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(adxr.next(bar(11., 12., 8.)).ok(), Some(Some(100.)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adxr {
+	adx: Adx,
+	window: Ring<f64>,
+	value: Option<f64>,
+}
+
+impl Adxr {
+	/// Creates a new average directional movement index rating over `period`
+	/// bars.
+	pub fn new(period: usize) -> Result<Adxr> {
+		Ok(Adxr {
+			adx: Adx::new(period)?,
+			window: Ring::new(period),
+			value: None,
+		})
+	}
+
+	/// Returns the current value, or `None` during warm-up.
+	pub fn current(&self) -> Option<f64> {
+		self.value
+	}
+}
+
+impl Default for Adxr {
+	fn default() -> Adxr {
+		Adxr::new(14).unwrap()
+	}
+}
+
+impl Indicator for Adxr {
+	type Input = Bar;
+	type Output = f64;
+
+	fn next(&mut self, bar: Bar) -> Result<Option<f64>> {
+		if let Some(adx) = self.adx.next(bar)? {
+			self.window.push(adx);
+			if let Some(old) = self.window.get(self.window.capacity() - 1) {
+				self.value = Some((adx + old) / 2.);
+			}
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.adx.reset();
+		self.window.clear();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.adx.lookback() + self.window.capacity() - 1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the average directional movement index rating for every bar of
+/// the series.
+///
+/// The result is aligned with the input so that the first `3 * period - 2`
+/// positions are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(11., 12., 8., 11., 0., 1).unwrap(),
+/// ];
+/// let values = trend::adxr_series(&bars, 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some(100.)]));
+/// ```
+pub fn adxr_series(bars: &[Bar], period: usize) -> Result<Vec<Option<f64>>> {
+	analysis::series(Adxr::new(period)?, bars)
+}
+
+/// Direction of the trend followed by a stop and reverse indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+	/// Rising trend, the stop is below the price.
+	Long,
+	/// Falling trend, the stop is above the price.
+	Short,
+}
+
+/// Parabolic stop and reverse (SAR) developed by J. Welles Wilder Jr. trails
+/// the price with a stop that accelerates towards the extreme price of the
+/// trend. Each time the trend makes a new extreme the acceleration factor
+/// grows by `step` up to `maximum`. When the price crosses the stop the trend
+/// is reversed, the stop is placed at the extreme price of the previous trend
+/// and the acceleration factor starts again from `start`. Wilder suggested
+/// 0.02 for the start and the step and 0.2 for the maximum, which
+/// `ParabolicSar` defaults to.
+///
+/// The stop never moves into the range of the current or previous bar. The
+/// initial direction is short if the first two bars have a minus directional
+/// movement and long otherwise.
+///
+/// The output is the stop for the current bar, the direction of the trend and
+/// whether the trend was reversed on the current bar. The first value is
+/// available after 2 bars.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::{Direction, ParabolicSar};
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut sar = ParabolicSar::default();
+/// assert_eq!(sar.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(sar.next(bar(11., 12., 9.)).ok(), Some(Some((8., Direction::Long, false))));
+/// assert_eq!(sar.next(bar(7., 9., 6.)).ok(), Some(Some((12., Direction::Short, true))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParabolicSar {
+	start: f64,
+	step: f64,
+	maximum: f64,
+	previous: Option<Bar>,
+	direction: Direction,
+	stop: f64,
+	extreme: f64,
+	acceleration: f64,
+	value: Option<(f64, Direction, bool)>,
+}
+
+impl ParabolicSar {
+	/// Creates a new parabolic SAR.
+	///
+	/// # Arguments
+	///
+	/// * `start` - initial acceleration factor, at most `maximum`
+	/// * `step` - increase of the acceleration factor on each new extreme
+	/// * `maximum` - maximum acceleration factor, at most 1
+	///
+	/// All factors must be greater than 0.
+	pub fn new(start: f64, step: f64, maximum: f64) -> Result<ParabolicSar> {
+		analysis::check_finite(&[start, step, maximum])?;
+		for factor in &[start, step, maximum] {
+			analysis::check_alpha(*factor)?;
+		}
+		if start > maximum {
+			return Err(AnalysisError::InvalidAlpha(start));
+		}
+		Ok(ParabolicSar {
+			start,
+			step,
+			maximum,
+			previous: None,
+			direction: Direction::Long,
+			stop: 0.,
+			extreme: 0.,
+			acceleration: start,
+			value: None,
+		})
+	}
+
+	/// Returns the current stop, direction and reversal flag, or `None` during
+	/// warm-up.
+	pub fn current(&self) -> Option<(f64, Direction, bool)> {
+		self.value
+	}
+
+	fn update(&mut self, bar: &Bar, previous: &Bar) {
+		let (high, low) = (bar.high(), bar.low());
+		let (previous_high, previous_low) = (previous.high(), previous.low());
+		let reversal = match self.direction {
+			Direction::Long => low <= self.stop,
+			Direction::Short => high >= self.stop,
+		};
+		if reversal {
+			self.direction = match self.direction {
+				Direction::Long => Direction::Short,
+				Direction::Short => Direction::Long,
+			};
+			self.stop = self.extreme;
+			self.acceleration = self.start;
+			self.extreme = match self.direction {
+				Direction::Long => low,
+				Direction::Short => high,
+			};
+		}
+
+		let stop = match self.direction {
+			Direction::Long => self.stop.min(previous_low).min(low),
+			Direction::Short => self.stop.max(previous_high).max(high),
+		};
+		self.value = Some((stop, self.direction, reversal));
+
+		let extreme = match self.direction {
+			Direction::Long if reversal => high,
+			Direction::Short if reversal => low,
+			Direction::Long => self.extreme.max(high),
+			Direction::Short => self.extreme.min(low),
+		};
+		if !reversal && extreme != self.extreme {
+			self.acceleration = (self.acceleration + self.step).min(self.maximum);
+		}
+		self.extreme = extreme;
+		self.stop = smooth(self.extreme, stop, self.acceleration);
+		self.stop = match self.direction {
+			Direction::Long => self.stop.min(previous_low).min(low),
+			Direction::Short => self.stop.max(previous_high).max(high),
+		};
+	}
+}
+
+impl Default for ParabolicSar {
+	fn default() -> ParabolicSar {
+		ParabolicSar::new(0.02, 0.02, 0.2).unwrap()
+	}
+}
+
+impl Indicator for ParabolicSar {
+	type Input = Bar;
+	type Output = (f64, Direction, bool);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, Direction, bool)>> {
+		let previous = match self.previous.replace(bar) {
+			Some(previous) => previous,
+			None => return Ok(None),
+		};
+		match self.value {
+			Some(_) => self.update(&bar, &previous),
+			None => {
+				let (_, minus) = directional_movement(bar.high(), bar.low(),
+					previous.high(), previous.low())?;
+				let (direction, stop, extreme) = match minus > 0. {
+					true => (Direction::Short, previous.high(), bar.low()),
+					false => (Direction::Long, previous.low(), bar.high()),
+				};
+				self.direction = direction;
+				self.stop = stop;
+				self.extreme = extreme;
+				self.update(&bar, &bar);
+			},
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		*self = ParabolicSar::new(self.start, self.step, self.maximum).unwrap();
+	}
+
+	fn lookback(&self) -> usize {
+		1
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the parabolic SAR for every bar of the series.
+///
+/// The result is aligned with the input so that the first position is
+/// `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `start` - initial acceleration factor
+/// * `step` - increase of the acceleration factor on each new extreme
+/// * `maximum` - maximum acceleration factor
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+/// use stat::analysis::trend::Direction;
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(11., 12., 9., 11., 0., 1).unwrap(),
+/// ];
+/// let values = trend::parabolic_sar_series(&bars, 0.02, 0.02, 0.2);
+/// assert_eq!(values.ok(), Some(vec![None, Some((8., Direction::Long, false))]));
+/// ```
+pub fn parabolic_sar_series(bars: &[Bar], start: f64, step: f64, maximum: f64)
+	-> Result<Vec<Option<(f64, Direction, bool)>>> {
+	analysis::series(ParabolicSar::new(start, step, maximum)?, bars)
+}
+
+/// Aroon indicator developed by Tushar Chande measures how recently the
+/// highest high and the lowest low of the last `period + 1` bars were made.
+/// Aroon up is `100 * (period - bars since the highest high) / period` and
+/// Aroon down is calculated the same way from the lowest low. The Aroon
+/// oscillator is Aroon up minus Aroon down. If the extreme price was made on
+/// several bars the latest one is used.
+///
+/// The output is `(up, down, oscillator)`. The first value is available
+/// after `period + 1` bars.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::Aroon;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut aroon = Aroon::new(2).unwrap();
+/// assert_eq!(aroon.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(aroon.next(bar(8., 9., 7.)).ok(), Some(None));
+/// assert_eq!(aroon.next(bar(9., 11., 8.)).ok(), Some(Some((100., 50., 50.))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Aroon {
+	high: Extremum,
+	low: Extremum,
+	value: Option<(f64, f64, f64)>,
+}
+
+impl Aroon {
+	/// Creates a new Aroon indicator over `period` bars.
+	pub fn new(period: usize) -> Result<Aroon> {
+		analysis::check_period(period)?;
+		Ok(Aroon {
+			high: Extremum::max(period + 1),
+			low: Extremum::min(period + 1),
+			value: None,
+		})
+	}
+
+	/// Returns the current `(up, down, oscillator)`, or `None` during
+	/// warm-up.
+	pub fn current(&self) -> Option<(f64, f64, f64)> {
+		self.value
+	}
+}
+
+impl Indicator for Aroon {
+	type Input = Bar;
+	type Output = (f64, f64, f64);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, f64, f64)>> {
+		self.high.next(bar.high());
+		self.low.next(bar.low());
+		let period = self.high.lookback() as f64;
+		if let (Some(high), Some(low)) = (self.high.age(), self.low.age()) {
+			let up = 100. * (period - high as f64) / period;
+			let down = 100. * (period - low as f64) / period;
+			self.value = Some((up, down, up - down));
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.high.reset();
+		self.low.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.high.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the Aroon indicator for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(8., 9., 7., 8., 0., 1).unwrap(),
+/// ];
+/// let values = trend::aroon_series(&bars, 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some((0., 100., -100.))]));
+/// ```
+pub fn aroon_series(bars: &[Bar], period: usize) -> Result<Vec<Option<(f64, f64, f64)>>> {
+	analysis::series(Aroon::new(period)?, bars)
+}
+
+/// Ichimoku Kinko Hyo developed by Goichi Hosoda is a set of lines built
+/// from the midpoints of the highest high and the lowest low over different
+/// periods:
+///
+/// * tenkan-sen (conversion line) - midpoint of the last `tenkan` bars
+/// * kijun-sen (base line) - midpoint of the last `kijun` bars
+/// * senkou span A (leading span A) - average of tenkan-sen and kijun-sen
+/// * senkou span B (leading span B) - midpoint of the last `senkou` bars
+/// * chikou span (lagging span) - closing price
+///
+/// The leading spans form the cloud and are plotted `displacement` bars
+/// ahead, the lagging span is plotted `displacement` bars behind. Hosoda used
+/// 9, 26 and 52 periods and the displacement 26, which `Ichimoku` defaults
+/// to.
+///
+/// `Ichimoku` returns the lines calculated from the current bar as
+/// `(tenkan, kijun, senkou_a, senkou_b)` without any displacement, the
+/// lagging span is the closing price of the bar. Use `ichimoku_series` to get
+/// the lines aligned with their displaced positions. The first value is
+/// available after `max(tenkan, kijun, senkou)` bars.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::Ichimoku;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut ichimoku = Ichimoku::new(1, 2, 3, 2).unwrap();
+/// assert_eq!(ichimoku.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(ichimoku.next(bar(8., 9., 7.)).ok(), Some(None));
+/// assert_eq!(ichimoku.next(bar(11., 12., 10.)).ok(), Some(Some((11., 9.5, 10.25, 9.5))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ichimoku {
+	tenkan: Midpoint,
+	kijun: Midpoint,
+	senkou: Midpoint,
+	displacement: usize,
+	value: Option<(f64, f64, f64, f64)>,
+}
+
+impl Ichimoku {
+	/// Creates a new Ichimoku Kinko Hyo.
+	///
+	/// # Arguments
+	///
+	/// * `tenkan` - number of bars of the conversion line
+	/// * `kijun` - number of bars of the base line
+	/// * `senkou` - number of bars of the leading span B
+	/// * `displacement` - number of bars the spans are displaced by
+	pub fn new(tenkan: usize, kijun: usize, senkou: usize, displacement: usize)
+		-> Result<Ichimoku> {
+		Ok(Ichimoku {
+			tenkan: Midpoint::new(tenkan)?,
+			kijun: Midpoint::new(kijun)?,
+			senkou: Midpoint::new(senkou)?,
+			displacement,
+			value: None,
+		})
+	}
+
+	/// Returns the current `(tenkan, kijun, senkou_a, senkou_b)`, or `None`
+	/// during warm-up.
+	pub fn current(&self) -> Option<(f64, f64, f64, f64)> {
+		self.value
+	}
+
+	/// Returns the number of bars the spans are displaced by.
+	pub fn displacement(&self) -> usize {
+		self.displacement
+	}
+}
+
+impl Default for Ichimoku {
+	fn default() -> Ichimoku {
+		Ichimoku::new(9, 26, 52, 26).unwrap()
+	}
+}
+
+impl Indicator for Ichimoku {
+	type Input = Bar;
+	type Output = (f64, f64, f64, f64);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, f64, f64, f64)>> {
+		let lines = (self.tenkan.next(&bar), self.kijun.next(&bar), self.senkou.next(&bar));
+		if let (Some(tenkan), Some(kijun), Some(senkou)) = lines {
+			self.value = Some((tenkan, kijun, (tenkan + kijun) / 2., senkou));
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.tenkan.reset();
+		self.kijun.reset();
+		self.senkou.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.tenkan.lookback().max(self.kijun.lookback()).max(self.senkou.lookback())
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Lines of the Ichimoku Kinko Hyo aligned with their displaced positions.
+///
+/// Position `i` of every line is the value plotted at bar `i`. The leading
+/// spans are `displacement` values longer than the input, the extra values
+/// are the cloud ahead of the last bar. The last `displacement` positions of
+/// the lagging span are `None`, since the closing prices plotted there are
+/// not known yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IchimokuSeries {
+	/// Conversion line.
+	pub tenkan: Vec<Option<f64>>,
+	/// Base line.
+	pub kijun: Vec<Option<f64>>,
+	/// Leading span A.
+	pub senkou_a: Vec<Option<f64>>,
+	/// Leading span B.
+	pub senkou_b: Vec<Option<f64>>,
+	/// Lagging span.
+	pub chikou: Vec<Option<f64>>,
+}
+
+/// Calculates the Ichimoku Kinko Hyo for every bar of the series.
+///
+/// Unlike the other series functions each line is available as soon as its
+/// own period is full, and the spans are shifted by the displacement as
+/// described in `IchimokuSeries`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `tenkan` - number of bars of the conversion line
+/// * `kijun` - number of bars of the base line
+/// * `senkou` - number of bars of the leading span B
+/// * `displacement` - number of bars the spans are displaced by
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(8., 9., 7., 8., 0., 1).unwrap(),
+/// ];
+/// let lines = trend::ichimoku_series(&bars, 1, 2, 2, 1).unwrap();
+/// assert_eq!(lines.tenkan, vec![Some(9.), Some(8.)]);
+/// assert_eq!(lines.senkou_b, vec![None, None, Some(8.5)]);
+/// assert_eq!(lines.chikou, vec![Some(8.), None]);
+/// ```
+pub fn ichimoku_series(bars: &[Bar], tenkan: usize, kijun: usize, senkou: usize,
+	displacement: usize) -> Result<IchimokuSeries> {
+	let mut ichimoku = Ichimoku::new(tenkan, kijun, senkou, displacement)?;
+	let needed = ichimoku.lookback() + 1;
+	if bars.len() < needed {
+		return Err(AnalysisError::InsufficientData { needed, got: bars.len() });
+	}
+
+	let length = bars.len();
+	let mut lines = IchimokuSeries {
+		tenkan: Vec::with_capacity(length),
+		kijun: Vec::with_capacity(length),
+		senkou_a: vec![None; displacement],
+		senkou_b: vec![None; displacement],
+		chikou: bars.iter().skip(displacement).map(|bar| Some(bar.close())).collect(),
+	};
+	for bar in bars {
+		ichimoku.next(*bar)?;
+		let (tenkan, kijun) = (ichimoku.tenkan.current(), ichimoku.kijun.current());
+		lines.tenkan.push(tenkan);
+		lines.kijun.push(kijun);
+		lines.senkou_a.push(match (tenkan, kijun) {
+			(Some(tenkan), Some(kijun)) => Some((tenkan + kijun) / 2.),
+			_ => None,
+		});
+		lines.senkou_b.push(ichimoku.senkou.current());
+	}
+	lines.chikou.resize(length, None);
+	Ok(lines)
+}
+
+/// Midpoint of the highest high and the lowest low of the last `period` bars.
+#[derive(Debug, Clone)]
+struct Midpoint {
+	high: Extremum,
+	low: Extremum,
+	value: Option<f64>,
+}
+
+impl Midpoint {
+	fn new(period: usize) -> Result<Midpoint> {
+		analysis::check_period(period)?;
+		Ok(Midpoint {
+			high: Extremum::max(period),
+			low: Extremum::min(period),
+			value: None,
+		})
+	}
+
+	fn next(&mut self, bar: &Bar) -> Option<f64> {
+		if let (Some(high), Some(low)) = (self.high.next(bar.high()), self.low.next(bar.low())) {
+			self.value = Some((high + low) / 2.);
+		}
+		self.value
+	}
+
+	fn current(&self) -> Option<f64> {
+		self.value
+	}
+
+	fn reset(&mut self) {
+		self.high.reset();
+		self.low.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.high.lookback()
+	}
+}
+
+/// SuperTrend is a trailing stop built from bands placed `multiplier` times
+/// the average true range (ATR) above and below the midpoint of each bar.
+/// While the trend is rising the stop follows the lower band, which is only
+/// allowed to rise, and while the trend is falling it follows the upper band,
+/// which is only allowed to fall. A band is reset when the previous close
+/// crossed it. The trend flips when the close crosses the band it follows.
+///
+/// ATR is smoothed using Wilder's method. The trend starts as long. The
+/// output is the stop and the direction of the trend, and the first value is
+/// available after `period + 1` bars. `SuperTrend` defaults to 10 periods and
+/// the multiplier 3.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::{Direction, SuperTrend};
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut super_trend = SuperTrend::new(1, 1.).unwrap();
+/// assert_eq!(super_trend.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(super_trend.next(bar(11., 12., 9.)).ok(), Some(Some((7.5, Direction::Long))));
+/// assert_eq!(super_trend.next(bar(7., 9., 6.)).ok(), Some(Some((12.5, Direction::Short))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuperTrend {
+	atr: Atr,
+	multiplier: f64,
+	previous_close: f64,
+	upper: f64,
+	lower: f64,
+	value: Option<(f64, Direction)>,
+}
+
+impl SuperTrend {
+	/// Creates a new SuperTrend.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of periods of the average true range
+	/// * `multiplier` - multiplier of the average true range, greater than 0
+	pub fn new(period: usize, multiplier: f64) -> Result<SuperTrend> {
+		analysis::check_finite(&[multiplier])
+			.map_err(|err| analysis::locate(err, 1))?;
+		analysis::check_constant(multiplier)?;
+		Ok(SuperTrend {
+			atr: Atr::new(period)?,
+			multiplier,
+			previous_close: 0.,
+			upper: 0.,
+			lower: 0.,
+			value: None,
+		})
+	}
+
+	/// Returns the current stop and direction, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, Direction)> {
+		self.value
+	}
+}
+
+impl Default for SuperTrend {
+	fn default() -> SuperTrend {
+		SuperTrend::new(10, 3.).unwrap()
+	}
+}
+
+impl Indicator for SuperTrend {
+	type Input = Bar;
+	type Output = (f64, Direction);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, Direction)>> {
+		let atr = match self.atr.next(&bar)? {
+			Some(atr) => atr,
+			None => return Ok(None),
+		};
+		let middle = (bar.high() + bar.low()) / 2.;
+		let upper = middle + self.multiplier * atr;
+		let lower = middle - self.multiplier * atr;
+		let close = bar.close();
+
+		let direction = match self.value {
+			Some((_, direction)) => {
+				if upper < self.upper || self.previous_close > self.upper {
+					self.upper = upper;
+				}
+				if lower > self.lower || self.previous_close < self.lower {
+					self.lower = lower;
+				}
+				match direction {
+					Direction::Long if close < self.lower => Direction::Short,
+					Direction::Short if close > self.upper => Direction::Long,
+					_ => direction,
+				}
+			},
+			None => {
+				self.upper = upper;
+				self.lower = lower;
+				Direction::Long
+			},
+		};
+		self.previous_close = close;
+		self.value = Some(match direction {
+			Direction::Long => (self.lower, direction),
+			Direction::Short => (self.upper, direction),
+		});
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.atr.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.atr.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the SuperTrend for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of periods of the average true range
+/// * `multiplier` - multiplier of the average true range
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+/// use stat::analysis::trend::Direction;
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(11., 12., 9., 11., 0., 1).unwrap(),
+/// ];
+/// let values = trend::super_trend_series(&bars, 1, 1.);
+/// assert_eq!(values.ok(), Some(vec![None, Some((7.5, Direction::Long))]));
+/// ```
+pub fn super_trend_series(bars: &[Bar], period: usize, multiplier: f64)
+	-> Result<Vec<Option<(f64, Direction)>>> {
+	analysis::series(SuperTrend::new(period, multiplier)?, bars)
+}
+
+/// Chandelier exit developed by Charles Le Beau is a trailing stop hung from
+/// the extreme price of the last `period` bars. The long stop is the highest
+/// high minus `multiplier` times the average true range (ATR) and the short
+/// stop is the lowest low plus `multiplier` times ATR. While the previous
+/// close stays on the right side of a stop, the stop is only allowed to move
+/// in the direction of the trend.
+///
+/// The trend turns short when the close falls below the previous long stop
+/// and long when the close rises above the previous short stop. ATR is
+/// smoothed using Wilder's method and the trend starts as long. The output is
+/// the stop of the current trend and its direction, and the first value is
+/// available after `period + 1` bars. `ChandelierExit` defaults to 22 periods
+/// and the multiplier 3.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::{ChandelierExit, Direction};
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut chandelier = ChandelierExit::new(1, 1.).unwrap();
+/// assert_eq!(chandelier.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(chandelier.next(bar(11., 12., 9.)).ok(), Some(Some((9., Direction::Long))));
+/// assert_eq!(chandelier.next(bar(7., 9., 6.)).ok(), Some(Some((11., Direction::Short))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChandelierExit {
+	atr: Atr,
+	high: Extremum,
+	low: Extremum,
+	multiplier: f64,
+	previous_close: f64,
+	stops: Option<(f64, f64)>,
+	value: Option<(f64, Direction)>,
+}
+
+impl ChandelierExit {
+	/// Creates a new Chandelier exit.
+	///
+	/// # Arguments
+	///
+	/// * `period` - number of periods of the extreme prices and the average
+	///   true range
+	/// * `multiplier` - multiplier of the average true range, greater than 0
+	pub fn new(period: usize, multiplier: f64) -> Result<ChandelierExit> {
+		analysis::check_finite(&[multiplier])
+			.map_err(|err| analysis::locate(err, 1))?;
+		analysis::check_constant(multiplier)?;
+		Ok(ChandelierExit {
+			atr: Atr::new(period)?,
+			high: Extremum::max(period),
+			low: Extremum::min(period),
+			multiplier,
+			previous_close: 0.,
+			stops: None,
+			value: None,
+		})
+	}
+
+	/// Returns the current stop and direction, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, Direction)> {
+		self.value
+	}
+
+	/// Returns the current long and short stops, or `None` during warm-up.
+	pub fn stops(&self) -> Option<(f64, f64)> {
+		self.stops
+	}
+}
+
+impl Default for ChandelierExit {
+	fn default() -> ChandelierExit {
+		ChandelierExit::new(22, 3.).unwrap()
+	}
+}
+
+impl Indicator for ChandelierExit {
+	type Input = Bar;
+	type Output = (f64, Direction);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, Direction)>> {
+		let extremes = (self.high.next(bar.high()), self.low.next(bar.low()));
+		let (high, low, atr) = match (extremes, self.atr.next(&bar)?) {
+			((Some(high), Some(low)), Some(atr)) => (high, low, atr),
+			_ => return Ok(None),
+		};
+		let mut long = high - self.multiplier * atr;
+		let mut short = low + self.multiplier * atr;
+		let close = bar.close();
+
+		let direction = match (self.stops, self.value) {
+			(Some((previous_long, previous_short)), Some((_, direction))) => {
+				if self.previous_close > previous_long {
+					long = long.max(previous_long);
+				}
+				if self.previous_close < previous_short {
+					short = short.min(previous_short);
+				}
+				if close > previous_short {
+					Direction::Long
+				} else if close < previous_long {
+					Direction::Short
+				} else {
+					direction
+				}
+			},
+			_ => Direction::Long,
+		};
+		self.previous_close = close;
+		self.stops = Some((long, short));
+		self.value = Some(match direction {
+			Direction::Long => (long, direction),
+			Direction::Short => (short, direction),
+		});
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.atr.reset();
+		self.high.reset();
+		self.low.reset();
+		self.stops = None;
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.atr.lookback()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the Chandelier exit for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of periods of the extreme prices and the average true
+///   range
+/// * `multiplier` - multiplier of the average true range
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+/// use stat::analysis::trend::Direction;
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(11., 12., 9., 11., 0., 1).unwrap(),
+/// ];
+/// let values = trend::chandelier_exit_series(&bars, 1, 1.);
+/// assert_eq!(values.ok(), Some(vec![None, Some((9., Direction::Long))]));
+/// ```
+pub fn chandelier_exit_series(bars: &[Bar], period: usize, multiplier: f64)
+	-> Result<Vec<Option<(f64, Direction)>>> {
+	analysis::series(ChandelierExit::new(period, multiplier)?, bars)
+}
+
+/// Average true range smoothed using Wilder's method. The first true range
+/// needs the previous close, so the first value is available after
+/// `period + 1` bars.
+#[derive(Debug, Clone)]
+struct Atr {
+	previous_close: Option<f64>,
+	ema: Ema,
+}
+
+impl Atr {
+	fn new(period: usize) -> Result<Atr> {
+		Ok(Atr {
+			previous_close: None,
+			ema: EmaConfig::new().smoothing(Smoothing::Wilder).build(period)?,
+		})
+	}
+
+	fn next(&mut self, bar: &Bar) -> Result<Option<f64>> {
+		match self.previous_close.replace(bar.close()) {
+			Some(close) => self.ema.next(true_range(bar, close)),
+			None => Ok(None),
+		}
+	}
+
+	fn reset(&mut self) {
+		self.previous_close = None;
+		self.ema.reset();
+	}
+
+	fn lookback(&self) -> usize {
+		self.ema.lookback() + 1
+	}
+}
+
+/// Vortex indicator developed by Etienne Botes and Douglas Siepman compares
+/// the upward and downward movement between consecutive bars. The positive
+/// vortex movement is the distance between the current high and the previous
+/// low, the negative vortex movement is the distance between the current low
+/// and the previous high. VI+ and VI- are the sums of the movements over
+/// `period` bars divided by the sum of the true ranges. A trend is starting
+/// when VI+ crosses above VI- or the other way round. Both are 0 if the true
+/// range was 0 for the whole window.
+///
+/// The output is `(VI+, VI-)`. The first value is available after
+/// `period + 1` bars.
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, Indicator};
+/// use stat::analysis::trend::Vortex;
+///
+/// let bar = |close, high, low| Bar::new(close, high, low, close, 0., 0).unwrap();
+/// let mut vortex = Vortex::new(1).unwrap();
+/// assert_eq!(vortex.next(bar(9., 10., 8.)).ok(), Some(None));
+/// assert_eq!(vortex.next(bar(11., 12., 8.)).ok(), Some(Some((1., 0.5))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Vortex {
+	previous: Option<Bar>,
+	window: Ring<(f64, f64, f64)>,
+	plus: Sum,
+	minus: Sum,
+	range: Sum,
+	value: Option<(f64, f64)>,
+}
+
+impl Vortex {
+	/// Creates a new vortex indicator over `period` bars.
+	pub fn new(period: usize) -> Result<Vortex> {
+		analysis::check_period(period)?;
+		Ok(Vortex {
+			previous: None,
+			window: Ring::new(period),
+			plus: Sum::default(),
+			minus: Sum::default(),
+			range: Sum::default(),
+			value: None,
+		})
+	}
+
+	/// Returns the current `(VI+, VI-)`, or `None` during warm-up.
+	pub fn current(&self) -> Option<(f64, f64)> {
+		self.value
+	}
+}
+
+impl Indicator for Vortex {
+	type Input = Bar;
+	type Output = (f64, f64);
+
+	fn next(&mut self, bar: Bar) -> Result<Option<(f64, f64)>> {
+		let previous = match self.previous.replace(bar) {
+			Some(previous) => previous,
+			None => return Ok(None),
+		};
+		let plus = (bar.high() - previous.low()).abs();
+		let minus = (bar.low() - previous.high()).abs();
+		let range = true_range(&bar, previous.close());
+		if let Some((plus, minus, range)) = self.window.push((plus, minus, range)) {
+			self.plus.add(-plus);
+			self.minus.add(-minus);
+			self.range.add(-range);
+		}
+		self.plus.add(plus);
+		self.minus.add(minus);
+		self.range.add(range);
+
+		if self.window.is_full() {
+			let range = self.range.value();
+			self.value = Some(match range > 0. {
+				true => (self.plus.value() / range, self.minus.value() / range),
+				false => (0., 0.),
+			});
+		}
+		Ok(self.value)
+	}
+
+	fn reset(&mut self) {
+		self.previous = None;
+		self.window.clear();
+		self.plus.reset();
+		self.minus.reset();
+		self.range.reset();
+		self.value = None;
+	}
+
+	fn lookback(&self) -> usize {
+		self.window.capacity()
+	}
+
+	fn is_ready(&self) -> bool {
+		self.value.is_some()
+	}
+}
+
+/// Calculates the vortex indicator for every bar of the series.
+///
+/// The result is aligned with the input so that the first `period` positions
+/// are `None`.
+///
+/// # Arguments
+///
+/// * `bars` - array of bars
+/// * `period` - number of bars
+///
+/// # Example
+///
+/// ```
+/// use stat::analysis::{Bar, trend};
+///
+/// let bars = [
+///     Bar::new(9., 10., 8., 9., 0., 0).unwrap(),
+///     Bar::new(11., 12., 8., 11., 0., 1).unwrap(),
+/// ];
+/// let values = trend::vortex_series(&bars, 1);
+/// assert_eq!(values.ok(), Some(vec![None, Some((1., 0.5))]));
+/// ```
+pub fn vortex_series(bars: &[Bar], period: usize) -> Result<Vec<Option<(f64, f64)>>> {
+	analysis::series(Vortex::new(period)?, bars)
+}
+
+/// Returns the true range of the bar, the greatest of the bar's range and the
+/// distances of its high and low from the previous closing price.
+fn true_range(bar: &Bar, previous_close: f64) -> f64 {
+	(bar.high() - bar.low())
+		.max((bar.high() - previous_close).abs())
+		.max((bar.low() - previous_close).abs())
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate math;
+	use analysis::{AnalysisError, Bar, Indicator, Result};
+	use self::math::round::{half_to_even, half_up};
+
+	// half_up rounds negative values towards zero whenever the first dropped
+	// digit is 5, so round the magnitude of signed values instead.
+	fn signed_half_up(value: f64, scale: u8) -> f64 {
+		half_up(value.abs(), scale).copysign(value)
+	}
+
+	fn to_bars(data: &[(f64, f64, f64, f64)]) -> Vec<Bar> {
+		data.iter()
+			.enumerate()
+			.map(|(i, &(close, high, low, volume))| {
+				Bar::new(close, high, low, close, volume, i as u64).unwrap()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn exponential_moving_average() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 21] = [
+			22.221000, 22.208091, 22.241165, 22.266408, 22.328879, 22.516356,
+			22.795200, 22.968800, 23.125382, 23.275312, 23.339801, 23.427110,
+			23.507635, 23.533520, 23.471062, 23.403596, 23.390215, 23.261085,
+			23.231797, 23.080561, 22.915004,
+		];
+		let tests: [(&[f64], Option<f64>, Result<f64>); 24] = [
+			(&values[0..0], None, Err(AnalysisError::SliceIsEmpty)),
+			(&[22.27, f64::NAN], None, Err(AnalysisError::NonFiniteInput { index: 1 })),
+			(&[f64::INFINITY], Some(22.), Err(AnalysisError::NonFiniteInput { index: 0 })),
+			(&values[0..10], None, Ok(results[0])),
+			(&values[1..11], Some(results[0]), Ok(results[1])),
+			(&values[2..12], Some(results[1]), Ok(results[2])),
+			(&values[3..13], Some(results[2]), Ok(results[3])),
+			(&values[4..14], Some(results[3]), Ok(results[4])),
+			(&values[5..15], Some(results[4]), Ok(results[5])),
+			(&values[6..16], Some(results[5]), Ok(results[6])),
+			(&values[7..17], Some(results[6]), Ok(results[7])),
+			(&values[8..18], Some(results[7]), Ok(results[8])),
+			(&values[9..19], Some(results[8]), Ok(results[9])),
+			(&values[10..20], Some(results[9]), Ok(results[10])),
+			(&values[11..21], Some(results[10]), Ok(results[11])),
+			(&values[12..22], Some(results[11]), Ok(results[12])),
+			(&values[13..23], Some(results[12]), Ok(results[13])),
+			(&values[14..24], Some(results[13]), Ok(results[14])),
+			(&values[15..25], Some(results[14]), Ok(results[15])),
+			(&values[16..26], Some(results[15]), Ok(results[16])),
+			(&values[17..27], Some(results[16]), Ok(results[17])),
+			(&values[18..28], Some(results[17]), Ok(results[18])),
+			(&values[19..29], Some(results[18]), Ok(results[19])),
+			(&values[20..30], Some(results[19]), Ok(results[20])),
+		];
+
+		for test in &tests {
+			let result = super::exponential_moving_average(test.0, test.1);
+			match (result, test.2.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert_eq!(half_to_even(val, 6), *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn ema() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 21] = [
+			22.221000, 22.208091, 22.241165, 22.266408, 22.328879, 22.516356,
+			22.795200, 22.968800, 23.125382, 23.275312, 23.339801, 23.427110,
+			23.507635, 23.533520, 23.471062, 23.403596, 23.390215, 23.261085,
+			23.231797, 23.080561, 22.915004,
+		];
+
+		let mut ema = super::Ema::new(10).unwrap();
+		assert_eq!(ema.lookback(), 9);
+		for (i, value) in values.iter().enumerate() {
+			let result = ema.next(*value).unwrap();
+			assert_eq!(result, ema.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(ema.is_ready());
+					assert_eq!(half_to_even(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!ema.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		match ema.next(f64::NAN) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::NonFiniteInput { index: 0 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		assert!(super::Ema::new(0).is_err());
+
+		ema.reset();
+		assert!(!ema.is_ready());
+		assert_eq!(ema.current(), None);
+		for value in &values[..10] {
+			ema.next(*value).unwrap();
+		}
+		assert_eq!(half_to_even(ema.current().unwrap(), 6), results[0]);
+	}
+
+	#[test]
+	fn ema_series() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 21] = [
+			22.221000, 22.208091, 22.241165, 22.266408, 22.328879, 22.516356,
+			22.795200, 22.968800, 23.125382, 23.275312, 23.339801, 23.427110,
+			23.507635, 23.533520, 23.471062, 23.403596, 23.390215, 23.261085,
+			23.231797, 23.080561, 22.915004,
+		];
+
+		let series = super::ema_series(&values, 10).unwrap();
+		assert_eq!(series.len(), values.len());
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert_eq!(half_to_even(value.unwrap(), 6), *exp);
+		}
+
+		let tests: [(&[f64], usize, AnalysisError); 3] = [
+			(&values[..9], 10, AnalysisError::InsufficientData { needed: 10, got: 9 }),
+			(&values, 0, AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., f64::NAN], 2, AnalysisError::NonFiniteInput { index: 2 }),
+		];
+
+		for test in &tests {
+			match super::ema_series(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn ema_config() {
+		use super::{EmaConfig, Seed, Smoothing};
+
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let wilder: [f64; 21] = [
+			22.221000, 22.213900, 22.231510, 22.246359, 22.282723, 22.390451,
+			22.556406, 22.675765, 22.791189, 22.907070, 22.979363, 23.063427,
+			23.144084, 23.194675, 23.194208, 23.184787, 23.199308, 23.147378,
+			23.142640, 23.068376, 22.978538,
+		];
+		let first: [f64; 30] = [
+			22.270000, 22.255455, 22.223554, 22.213817, 22.207668, 22.193547,
+			22.200175, 22.241961, 22.241604, 22.250404, 22.232148, 22.260849,
+			22.282513, 22.342056, 22.527137, 22.804021, 22.976017, 23.131287,
+			23.280144, 23.343754, 23.430344, 23.510282, 23.535685, 23.472833,
+			23.405045, 23.391401, 23.262055, 23.232591, 23.081210, 22.915536,
+		];
+		let custom: [f64; 30] = [
+			22.270000, 22.250000, 22.207500, 22.198125, 22.193594, 22.177695,
+			22.190771, 22.250579, 22.247934, 22.258450, 22.231338, 22.271003,
+			22.298253, 22.376189, 22.622142, 22.979107, 23.171830, 23.336372,
+			23.489779, 23.524834, 23.598626, 23.666469, 23.662352, 23.544264,
+			23.433198, 23.407399, 23.225549, 23.194162, 22.995621, 22.789216,
+		];
+		let tests: [(EmaConfig, usize, &[f64]); 3] = [
+			(EmaConfig::new().smoothing(Smoothing::Wilder), 9, &wilder),
+			(EmaConfig::new().seed(Seed::First), 0, &first),
+			(EmaConfig::new().smoothing(Smoothing::Custom(0.25)).seed(Seed::First), 0, &custom),
+		];
+
+		for test in &tests {
+			let mut ema = test.0.build(10).unwrap();
+			assert_eq!(ema.lookback(), test.1);
+			for (i, value) in values.iter().enumerate() {
+				let result = ema.next(*value).unwrap();
+				match i.checked_sub(test.1) {
+					Some(j) => assert_eq!(half_up(result.unwrap(), 6), test.2[j]),
+					None => assert_eq!(result, None),
+				}
+			}
+		}
+
+		let tests: [(EmaConfig, &[f64], Option<f64>, f64); 4] = [
+			(EmaConfig::default(), &values[0..10], None, 22.221000),
+			(EmaConfig::new().seed(Seed::First), &values[0..10], None, 22.250404),
+			(EmaConfig::new().smoothing(Smoothing::Wilder).seed(Seed::First),
+				&values[0..10], None, 22.246998),
+			(EmaConfig::new().smoothing(Smoothing::Wilder), &values[1..11], Some(22.221), 22.2139),
+		];
+
+		for test in &tests {
+			let result = test.0.moving_average(test.1, test.2).unwrap();
+			assert_eq!(half_up(result, 6), test.3);
+		}
+		match EmaConfig::new().seed(Seed::First).moving_average(&[], None) {
+			Err(err) => assert_eq!(err.to_string(), AnalysisError::SliceIsEmpty.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+
+		let tests: [(Smoothing, usize, AnalysisError); 5] = [
+			(Smoothing::Exponential, 0, AnalysisError::InvalidPeriod(0)),
+			(Smoothing::Custom(0.), 10, AnalysisError::InvalidAlpha(0.)),
+			(Smoothing::Custom(1.5), 10, AnalysisError::InvalidAlpha(1.5)),
+			(Smoothing::Custom(-0.5), 10, AnalysisError::InvalidAlpha(-0.5)),
+			(Smoothing::Custom(f64::NAN), 10, AnalysisError::InvalidAlpha(f64::NAN)),
+		];
+
+		for test in &tests {
+			match EmaConfig::new().smoothing(test.0).build(test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn macd() {
+		let values: [f64; 50] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17, 22.05, 22.41, 22.63, 22.89, 23.12, 23.04,
+			22.78, 22.56, 22.93, 23.27, 23.58, 23.44, 23.81, 24.12, 24.37,
+			24.05, 23.69, 23.91, 24.28, 24.46,
+		];
+		let macd_results: [(f64, f64, f64); 17] = [
+			(0.027638, 0.188203, -0.160565), (0.054353, 0.161433, -0.107081),
+			(0.068282, 0.142803, -0.074521), (0.057676, 0.125778, -0.068102),
+			(0.031159, 0.106854, -0.075695), (0.039545, 0.093392, -0.053847),
+			(0.072786, 0.089271, -0.016485), (0.122730, 0.095963, 0.026767),
+			(0.149293, 0.106629, 0.042664), (0.197919, 0.124887, 0.073032),
+			(0.258490, 0.151608, 0.106883), (0.322943, 0.185875, 0.137069),
+			(0.344234, 0.217547, 0.126687), (0.328273, 0.239692, 0.088581),
+			(0.329577, 0.257669, 0.071908), (0.356359, 0.277407, 0.078952),
+			(0.387640, 0.299453, 0.088186),
+		];
+		let ppo_results: [(f64, f64, f64); 17] = [
+			(0.121585, 0.823079, -0.701494), (0.238802, 0.706223, -0.467421),
+			(0.299727, 0.624924, -0.325197), (0.253173, 0.550574, -0.297401),
+			(0.136874, 0.467834, -0.330960), (0.173616, 0.408990, -0.235374),
+			(0.319048, 0.391002, -0.071954), (0.536635, 0.420129, 0.116506),
+			(0.651580, 0.466419, 0.185161), (0.861305, 0.545396, 0.315909),
+			(1.120775, 0.660472, 0.460304), (1.394384, 0.807254, 0.587130),
+			(1.482092, 0.942222, 0.539870), (1.411287, 1.036035, 0.375252),
+			(1.413970, 1.111622, 0.302348), (1.524165, 1.194130, 0.330034),
+			(1.652303, 1.285765, 0.366538),
+		];
+		let round = |(a, b, c): (f64, f64, f64)|
+			(signed_half_up(a, 6), signed_half_up(b, 6), signed_half_up(c, 6));
+
+		let tests = [
+			(super::Macd::default(), &macd_results),
+			(super::Macd::ppo(12, 26, 9).unwrap(), &ppo_results),
+		];
+		for test in &tests {
+			let mut macd = test.0.clone();
+			assert_eq!(macd.lookback(), 33);
+			for (i, value) in values.iter().enumerate() {
+				let result = macd.next(*value).unwrap();
+				assert_eq!(result, macd.current());
+				match i.checked_sub(33) {
+					Some(j) => {
+						assert!(macd.is_ready());
+						assert_eq!(round(result.unwrap()), test.1[j]);
+					},
+					None => {
+						assert!(!macd.is_ready());
+						assert_eq!(result, None);
+					},
+				}
+			}
+
+			macd.reset();
+			assert!(!macd.is_ready());
+			assert_eq!(macd.current(), None);
+		}
+
+		let series = super::macd_series(&values, 12, 26, 9).unwrap();
+		assert_eq!(series[..33], [None; 33]);
+		for (value, exp) in series[33..].iter().zip(macd_results.iter()) {
+			assert_eq!(round(value.unwrap()), *exp);
+		}
+		let series = super::ppo_series(&values, 12, 26, 9).unwrap();
+		assert_eq!(series[..33], [None; 33]);
+		for (value, exp) in series[33..].iter().zip(ppo_results.iter()) {
+			assert_eq!(round(value.unwrap()), *exp);
+		}
+
+		match super::macd_series(&values, 12, 26, 0) {
+			Err(err) => assert_eq!(err.to_string(), AnalysisError::InvalidPeriod(0).to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		match super::ppo_series(&values[..33], 12, 26, 9) {
+			Err(err) => assert_eq!(err.to_string(),
+				AnalysisError::InsufficientData { needed: 34, got: 33 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+
+		let series = super::ppo_series(&[0.; 4], 1, 2, 2).unwrap();
+		assert_eq!(series, vec![None, None, Some((0., 0., 0.)), Some((0., 0., 0.))]);
+	}
+
+	#[test]
+	fn dema() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 22] = [
+			22.305511, 22.307534, 22.228707, 22.318261, 22.360478, 22.509188,
+			23.003150, 23.645671, 23.809495, 23.920140, 24.025966, 23.886782,
+			23.901946, 23.927359, 23.810058, 23.484584, 23.257198, 23.266448,
+			22.916510, 22.968036, 22.616711, 22.308709,
+		];
+
+		let mut dema = super::Dema::new(5).unwrap();
+		assert_eq!(dema.lookback(), 8);
+		for (i, value) in values.iter().enumerate() {
+			let result = dema.next(*value).unwrap();
+			assert_eq!(result, dema.current());
+			match i.checked_sub(8) {
+				Some(j) => {
+					assert!(dema.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!dema.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		dema.reset();
+		assert!(!dema.is_ready());
+		assert_eq!(dema.current(), None);
+
+		let series = super::dema_series(&values, 5).unwrap();
+		assert_eq!(series[..8], [None; 8]);
+		for (value, exp) in series[8..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert!(super::Dema::new(0).is_err());
+	}
+
+	#[test]
+	fn tema() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 18] = [
+			22.373160, 22.551247, 23.150139, 23.878440, 23.944843, 23.980325,
+			24.040768, 23.811055, 23.824147, 23.856373, 23.709381, 23.319272,
+			23.094590, 23.179227, 22.779526, 22.920701, 22.512917, 22.193277,
+		];
+
+		let mut tema = super::Tema::new(5).unwrap();
+		assert_eq!(tema.lookback(), 12);
+		for (i, value) in values.iter().enumerate() {
+			let result = tema.next(*value).unwrap();
+			assert_eq!(result, tema.current());
+			match i.checked_sub(12) {
+				Some(j) => {
+					assert!(tema.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!tema.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		tema.reset();
+		assert!(!tema.is_ready());
+		assert_eq!(tema.current(), None);
+
+		let series = super::tema_series(&values, 5).unwrap();
+		assert_eq!(series[..12], [None; 12]);
+		for (value, exp) in series[12..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert!(super::Tema::new(0).is_err());
+	}
+
+	#[test]
+	fn t3() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 18] = [
+			22.324144, 22.429098, 22.757854, 23.289149, 23.641008, 23.829065,
+			23.940540, 23.895477, 23.859092, 23.857725, 23.800019, 23.591308,
+			23.357315, 23.260553, 23.049252, 22.975897, 22.772767, 22.499736,
+		];
+
+		let mut t3 = super::T3::new(3, 0.7).unwrap();
+		assert_eq!(t3.lookback(), 12);
+		for (i, value) in values.iter().enumerate() {
+			let result = t3.next(*value).unwrap();
+			assert_eq!(result, t3.current());
+			match i.checked_sub(12) {
+				Some(j) => {
+					assert!(t3.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!t3.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		t3.reset();
+		assert!(!t3.is_ready());
+		assert_eq!(t3.current(), None);
+
+		let series = super::t3_series(&values, 3, 0.7).unwrap();
+		assert_eq!(series[..12], [None; 12]);
+		for (value, exp) in series[12..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+
+		let tests: [(usize, f64, AnalysisError); 2] = [
+			(0, 0.7, AnalysisError::InvalidPeriod(0)),
+			(3, f64::NAN, AnalysisError::NonFiniteInput { index: 1 }),
+		];
+
+		for test in &tests {
+			match super::T3::new(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn zlema() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 20] = [
+			22.245000, 22.310556, 22.345988, 22.506879, 22.912017, 23.536013,
+			23.836899, 23.939811, 23.919853, 23.828774, 23.824602, 23.816913,
+			23.784266, 23.512207, 23.249494, 23.196273, 22.968212, 22.997499,
+			22.658054, 22.436265,
+		];
+
+		let mut zlema = super::Zlema::new(8).unwrap();
+		assert_eq!(zlema.lookback(), 10);
+		for (i, value) in values.iter().enumerate() {
+			let result = zlema.next(*value).unwrap();
+			assert_eq!(result, zlema.current());
+			match i.checked_sub(10) {
+				Some(j) => {
+					assert!(zlema.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!zlema.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		zlema.reset();
+		assert!(!zlema.is_ready());
+		assert_eq!(zlema.current(), None);
+
+		let series = super::zlema_series(&values, 8).unwrap();
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert!(super::Zlema::new(0).is_err());
+	}
+
+	#[test]
+	fn kama() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 20] = [
+			22.287435, 22.290281, 22.295101, 22.320087, 22.509725, 22.909065,
+			23.037890, 23.151713, 23.319917, 23.356757, 23.433765, 23.500037,
+			23.515755, 23.505853, 23.499344, 23.486540, 23.397714, 23.382728,
+			23.265897, 23.142281,
+		];
+
+		let mut kama = super::Kama::new(10, 2, 30).unwrap();
+		assert_eq!(kama.lookback(), 10);
+		for (i, value) in values.iter().enumerate() {
+			let result = kama.next(*value).unwrap();
+			assert_eq!(result, kama.current());
+			match i.checked_sub(10) {
+				Some(j) => {
+					assert!(kama.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!kama.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		kama.reset();
+		assert!(!kama.is_ready());
+		assert_eq!(kama.current(), None);
+
+		let series = super::kama_series(&values, 10, 2, 30).unwrap();
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert!(super::Kama::new(0, 2, 30).is_err());
+		assert!(super::Kama::new(10, 0, 30).is_err());
+		assert!(super::Kama::new(10, 2, 0).is_err());
+	}
+
+	#[test]
+	fn vidya() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 21] = [
+			22.240152, 22.239640, 22.245448, 22.249254, 22.266347, 22.360252,
+			22.524270, 22.607243, 22.711349, 22.818441, 22.876889, 22.943736,
+			23.011142, 23.043711, 23.045076, 23.048878, 23.057822, 23.032959,
+			23.035848, 23.001203, 22.941248,
+		];
+
+		let mut vidya = super::Vidya::new(14, 9).unwrap();
+		assert_eq!(vidya.lookback(), 9);
+		for (i, value) in values.iter().enumerate() {
+			let result = vidya.next(*value).unwrap();
+			assert_eq!(result, vidya.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(vidya.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!vidya.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		vidya.reset();
+		assert!(!vidya.is_ready());
+		assert_eq!(vidya.current(), None);
+
+		let series = super::vidya_series(&values, 14, 9).unwrap();
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert!(super::Vidya::new(0, 9).is_err());
+		assert!(super::Vidya::new(14, 0).is_err());
+	}
+
+	#[test]
+	fn mama() {
+		let values: [f64; 50] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17, 22.05, 22.41, 22.63, 22.89, 23.12, 23.04,
+			22.78, 22.56, 22.93, 23.27, 23.58, 23.44, 23.81, 24.12, 24.37,
+			24.05, 23.69, 23.91, 24.28, 24.46,
+		];
+		let results: [(f64, f64); 18] = [
+			(22.673186, 22.958173), (22.781593, 22.914028),
+			(22.798513, 22.911140), (22.810588, 22.908626),
+			(22.809058, 22.906137), (22.684529, 22.850735),
+			(22.696803, 22.846887), (22.725462, 22.843851),
+			(22.768189, 22.841959), (22.801780, 22.840955),
+			(23.305890, 22.957189), (23.346595, 22.966924),
+			(23.858298, 23.189767), (23.867883, 23.206720),
+			(23.858989, 23.223027), (23.867170, 23.274682),
+			(23.888157, 23.290275), (23.916749, 23.305937),
+		];
+
+		let mut mama = super::Mama::default();
+		assert_eq!(mama.lookback(), 32);
+		for (i, value) in values.iter().enumerate() {
+			let result = mama.next(*value).unwrap();
+			assert_eq!(result, mama.current());
+			match i.checked_sub(32) {
+				Some(j) => {
+					let (m, f) = result.unwrap();
+					assert!(mama.is_ready());
+					assert_eq!((half_up(m, 6), half_up(f, 6)), results[j]);
+				},
+				None => {
+					assert!(!mama.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		mama.reset();
+		assert!(!mama.is_ready());
+		assert_eq!(mama.current(), None);
+
+		let series = super::mama_series(&values, 0.5, 0.05).unwrap();
+		assert_eq!(series[..32], [None; 32]);
+		for (value, exp) in series[32..].iter().zip(results.iter()) {
+			let (m, f) = value.unwrap();
+			assert_eq!((half_up(m, 6), half_up(f, 6)), *exp);
+		}
+		let tests: [(f64, f64, AnalysisError); 6] = [
+			(0.5, f64::NAN, AnalysisError::NonFiniteInput { index: 1 }),
+			(1.5, 0.05, AnalysisError::InvalidAlpha(1.5)),
+			(0., 0.05, AnalysisError::InvalidAlpha(0.)),
+			(0.5, 0., AnalysisError::InvalidAlpha(0.)),
+			(0.5, -0.05, AnalysisError::InvalidAlpha(-0.05)),
+			(0.05, 0.5, AnalysisError::InvalidAlpha(0.5)),
+		];
+
+		for test in &tests {
+			match super::Mama::new(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+		assert!(super::Mama::new(1., 1.).is_ok());
+	}
+
+	#[test]
+	fn simple_moving_average() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 21] = [
+			22.221, 22.209, 22.229, 22.259, 22.303, 22.421, 22.613, 22.765,
+			22.905, 23.076, 23.210, 23.377, 23.525, 23.652, 23.710, 23.684,
+			23.612, 23.505, 23.432, 23.277, 23.131,
+		];
+		let tests: [(&[f64], Result<f64>); 23] = [
+			(&values[0..0], Err(AnalysisError::SliceIsEmpty)),
+			(&[22.27, f64::NAN], Err(AnalysisError::NonFiniteInput { index: 1 })),
+			(&values[0..10], Ok(results[0])),
+			(&values[1..11], Ok(results[1])),
+			(&values[2..12], Ok(results[2])),
+			(&values[3..13], Ok(results[3])),
+			(&values[4..14], Ok(results[4])),
+			(&values[5..15], Ok(results[5])),
+			(&values[6..16], Ok(results[6])),
+			(&values[7..17], Ok(results[7])),
+			(&values[8..18], Ok(results[8])),
+			(&values[9..19], Ok(results[9])),
+			(&values[10..20], Ok(results[10])),
+			(&values[11..21], Ok(results[11])),
+			(&values[12..22], Ok(results[12])),
+			(&values[13..23], Ok(results[13])),
+			(&values[14..24], Ok(results[14])),
+			(&values[15..25], Ok(results[15])),
+			(&values[16..26], Ok(results[16])),
+			(&values[17..27], Ok(results[17])),
+			(&values[18..28], Ok(results[18])),
+			(&values[19..29], Ok(results[19])),
+			(&values[20..30], Ok(results[20])),
+		];
+
+		for test in &tests {
+			let result = super::simple_moving_average(test.0);
+			match (result, test.1.as_ref()) {
+				(Ok(val), Ok(exp))
+					=> assert_eq!(half_to_even(val, 3), *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn sma() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+
+		let mut sma = super::Sma::new(10).unwrap();
+		assert_eq!(sma.lookback(), 9);
+		for (i, value) in values.iter().enumerate() {
+			let result = sma.next(*value).unwrap();
+			assert_eq!(result, sma.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					let exp = super::simple_moving_average(&values[j..i+1]).unwrap();
+					assert!(sma.is_ready());
+					assert!((result.unwrap() - exp).abs() < 1e-9);
+				},
+				None => {
+					assert!(!sma.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		match sma.next(f64::NAN) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::NonFiniteInput { index: 0 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		assert!(super::Sma::new(0).is_err());
+
+		sma.reset();
+		assert!(!sma.is_ready());
+		assert_eq!(sma.current(), None);
+		for value in &values[..10] {
+			sma.next(*value).unwrap();
+		}
+		let exp = super::simple_moving_average(&values[..10]).unwrap();
+		assert!((sma.current().unwrap() - exp).abs() < 1e-9);
+	}
+
+	#[test]
+	fn sma_series() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+
+		let series = super::sma_series(&values, 10).unwrap();
+		assert_eq!(series.len(), values.len());
+		assert_eq!(series[..9], [None; 9]);
+		for (i, value) in series.iter().enumerate().skip(9) {
+			let exp = super::simple_moving_average(&values[i-9..i+1]).unwrap();
+			assert!((value.unwrap() - exp).abs() < 1e-9);
+		}
+
+		let tests: [(&[f64], usize, AnalysisError); 3] = [
+			(&values[..9], 10, AnalysisError::InsufficientData { needed: 10, got: 9 }),
+			(&values, 0, AnalysisError::InvalidPeriod(0)),
+			(&[1., 2., f64::NAN], 2, AnalysisError::NonFiniteInput { index: 2 }),
+		];
+
+		for test in &tests {
+			match super::sma_series(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn weighted_moving_average() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 21] = [
+			22.242909, 22.230000, 22.262909, 22.290364, 22.354182, 22.546364,
+			22.842545, 23.049273, 23.242909, 23.432909, 23.533636, 23.644545,
+			23.734182, 23.756909, 23.672909, 23.562000, 23.497636, 23.328182,
+			23.254545, 23.066909, 22.865636,
+		];
+
+		for (i, exp) in results.iter().enumerate() {
+			let result = super::weighted_moving_average(&values[i..i+10]).unwrap();
+			assert_eq!(half_up(result, 6), *exp);
+		}
+
+		let tests: [(&[f64], Result<f64>); 4] = [
+			(&[], Err(AnalysisError::SliceIsEmpty)),
+			(&[22.27, f64::NAN], Err(AnalysisError::NonFiniteInput { index: 1 })),
+			(&[1.], Ok(1.)),
+			(&[1., 2., 3.], Ok(14. / 6.)),
+		];
+
+		for test in &tests {
+			let result = super::weighted_moving_average(test.0);
+			match (result, test.1.as_ref()) {
+				(Ok(val), Ok(exp)) => assert_eq!(val, *exp),
+				(Err(err), Err(exp))
+					=> assert_eq!(err.to_string(), exp.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn wma() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 21] = [
+			22.242909, 22.230000, 22.262909, 22.290364, 22.354182, 22.546364,
+			22.842545, 23.049273, 23.242909, 23.432909, 23.533636, 23.644545,
+			23.734182, 23.756909, 23.672909, 23.562000, 23.497636, 23.328182,
+			23.254545, 23.066909, 22.865636,
+		];
+
+		let mut wma = super::Wma::new(10).unwrap();
+		assert_eq!(wma.lookback(), 9);
+		for (i, value) in values.iter().enumerate() {
+			let result = wma.next(*value).unwrap();
+			assert_eq!(result, wma.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(wma.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!wma.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		wma.reset();
+		assert!(!wma.is_ready());
+		assert_eq!(wma.current(), None);
+
+		let series = super::wma_series(&values, 10).unwrap();
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert!(super::Wma::new(0).is_err());
+	}
+
+	#[test]
+	fn hull_moving_average() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 20] = [
+			22.292000, 22.285926, 22.318074, 22.437037, 22.793481, 23.400556,
+			23.904222, 24.185593, 24.254481, 24.108481, 23.964111, 23.875074,
+			23.787000, 23.574815, 23.295630, 23.124815, 22.900333, 22.832444,
+			22.645704, 22.376074,
+		];
+
+		for (i, exp) in results.iter().enumerate() {
+			let result = super::hull_moving_average(&values[..i+11], 9).unwrap();
+			assert_eq!(half_up(result, 6), *exp);
+		}
+
+		let tests: [(&[f64], usize, AnalysisError); 5] = [
+			(&[], 9, AnalysisError::SliceIsEmpty),
+			(&values[..10], 9, AnalysisError::InsufficientData { needed: 11, got: 10 }),
+			(&values, 0, AnalysisError::InvalidPeriod(0)),
+			(&values, 1, AnalysisError::InvalidPeriod(1)),
+			(&[1., f64::NAN, 3.], 2, AnalysisError::NonFiniteInput { index: 1 }),
+		];
+
+		for test in &tests {
+			match super::hull_moving_average(test.0, test.1) {
+				Err(err) => assert_eq!(err.to_string(), test.2.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn hma() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 20] = [
+			22.292000, 22.285926, 22.318074, 22.437037, 22.793481, 23.400556,
+			23.904222, 24.185593, 24.254481, 24.108481, 23.964111, 23.875074,
+			23.787000, 23.574815, 23.295630, 23.124815, 22.900333, 22.832444,
+			22.645704, 22.376074,
+		];
+
+		let mut hma = super::Hma::new(9).unwrap();
+		assert_eq!(hma.lookback(), 10);
+		for (i, value) in values.iter().enumerate() {
+			let result = hma.next(*value).unwrap();
+			assert_eq!(result, hma.current());
+			match i.checked_sub(10) {
+				Some(j) => {
+					assert!(hma.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!hma.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		hma.reset();
+		assert!(!hma.is_ready());
+		assert_eq!(hma.current(), None);
+
+		let series = super::hma_series(&values, 9).unwrap();
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+	}
+
+	#[test]
+	fn volume_weighted_moving_average() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 16] = [
+			125.898796, 126.246255, 126.237907, 126.179526, 126.258266,
+			126.113551, 125.789436, 125.478947, 125.936756, 126.129200,
+			126.316785, 126.859037, 127.175174, 127.336607, 127.035611,
+			127.150300,
+		];
+
+		for (i, exp) in results.iter().enumerate() {
+			let result = super::volume_weighted_moving_average(&bars[i..i+5]).unwrap();
+			assert_eq!(half_up(result, 6), *exp);
+		}
+
+		let flat = to_bars(&[(10., 10., 10., 0.), (20., 20., 20., 0.)]);
+		assert_eq!(super::volume_weighted_moving_average(&flat).ok(), Some(15.));
+		match super::volume_weighted_moving_average(&[]) {
+			Err(err) => assert_eq!(
+				err.to_string(), AnalysisError::SliceIsEmpty.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+	}
+
+	#[test]
+	fn vwma() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 16] = [
+			125.898796, 126.246255, 126.237907, 126.179526, 126.258266,
+			126.113551, 125.789436, 125.478947, 125.936756, 126.129200,
+			126.316785, 126.859037, 127.175174, 127.336607, 127.035611,
+			127.150300,
+		];
+
+		let mut vwma = super::Vwma::new(5).unwrap();
+		assert_eq!(vwma.lookback(), 4);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = vwma.next(*bar).unwrap();
+			assert_eq!(result, vwma.current());
+			match i.checked_sub(4) {
+				Some(j) => {
+					assert!(vwma.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!vwma.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		vwma.reset();
+		assert!(!vwma.is_ready());
+		assert_eq!(vwma.current(), None);
+
+		let series = super::vwma_series(&bars, 5).unwrap();
+		assert_eq!(series[..4], [None; 4]);
+		for (value, exp) in series[4..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert!(super::Vwma::new(0).is_err());
+
+		let quiet = to_bars(&[
+			(10., 10., 10., 0.7), (20., 20., 20., 0.1), (30., 30., 30., 0.),
+			(40., 40., 40., 0.), (50., 50., 50., 2.),
+		]);
+		let series = super::vwma_series(&quiet, 2).unwrap();
+		assert_eq!(series[3], Some(35.));
+		assert_eq!(series[4], Some(50.));
+		let value = super::volume_weighted_moving_average(&quiet[2..4]).unwrap();
+		assert_eq!(series[3], Some(value));
+	}
+
+	#[test]
+	fn directional_movement() {
+		let tests: [([f64; 4], (f64, f64)); 6] = [
+			([12., 9., 10., 8.], (2., 0.)),
+			([10., 6., 10., 8.], (0., 2.)),
+			([11., 7., 10., 8.], (0., 0.)),
+			([9., 8.5, 10., 8.], (0., 0.)),
+			([12., 6., 10., 8.], (0., 0.)),
+			([13., 6., 10., 8.], (3., 0.)),
+		];
+
+		for test in &tests {
+			let [high, low, previous_high, previous_low] = test.0;
+			let result = super::directional_movement(high, low, previous_high, previous_low);
+			assert_eq!(result.unwrap(), test.1);
+		}
+
+		let tests: [([f64; 4], AnalysisError); 3] = [
+			([8., 9., 10., 8.], AnalysisError::HighLessThanLow),
+			([12., 9., 8., 10.], AnalysisError::HighLessThanLow),
+			([12., 9., f64::NAN, 8.], AnalysisError::NonFiniteInput { index: 2 }),
+		];
+
+		for test in &tests {
+			let [high, low, previous_high, previous_low] = test.0;
+			match super::directional_movement(high, low, previous_high, previous_low) {
+				Err(err) => assert_eq!(err.to_string(), test.1.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+	}
+
+	#[test]
+	fn dmi() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, f64, f64); 15] = [
+			(21.618954, 15.498519, 16.489362), (18.551461, 18.064380, 1.330249),
+			(15.326073, 28.045313, 29.326341), (17.642704, 22.606917, 12.333567),
+			(15.266774, 27.547275, 28.683344), (12.773926, 37.508092, 49.190877),
+			(11.757830, 31.808673, 46.023532), (25.974651, 23.246555, 5.542522),
+			(30.148186, 20.359030, 19.381698), (25.656120, 20.558553, 11.030192),
+			(27.985979, 16.453371, 25.951342), (21.963366, 14.154133, 21.621744),
+			(17.966775, 11.578558, 21.621744), (13.044611, 16.983733, 13.118013),
+			(10.102378, 15.755543, 21.862414),
+		];
+		let round = |(a, b, c): (f64, f64, f64)| (half_up(a, 6), half_up(b, 6), half_up(c, 6));
+
+		let mut dmi = super::Dmi::new(5).unwrap();
+		assert_eq!(dmi.lookback(), 5);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = dmi.next(*bar).unwrap();
+			assert_eq!(result, dmi.current());
+			match i.checked_sub(5) {
+				Some(j) => {
+					assert!(dmi.is_ready());
+					assert_eq!(round(result.unwrap()), results[j]);
+				},
+				None => {
+					assert!(!dmi.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		dmi.reset();
+		assert!(!dmi.is_ready());
+		assert_eq!(dmi.current(), None);
+
+		let series = super::dmi_series(&bars, 5).unwrap();
+		assert_eq!(series[..5], [None; 5]);
+		for (value, exp) in series[5..].iter().zip(results.iter()) {
+			assert_eq!(round(value.unwrap()), *exp);
+		}
+		assert_eq!(super::Dmi::default().lookback(), 14);
+		assert!(super::Dmi::new(0).is_err());
+	}
+
+	#[test]
+	fn adx() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 11] = [
+			17.632573, 23.944233, 28.360093, 23.796579, 22.913603, 20.536921,
+			21.619805, 21.620193, 21.620503, 19.920005, 20.308487,
+		];
+
+		let mut adx = super::Adx::new(5).unwrap();
+		assert_eq!(adx.lookback(), 9);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = adx.next(*bar).unwrap();
+			assert_eq!(result, adx.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(adx.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!adx.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		adx.reset();
+		assert!(!adx.is_ready());
+		assert_eq!(adx.current(), None);
+
+		let series = super::adx_series(&bars, 5).unwrap();
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert_eq!(super::Adx::default().lookback(), 27);
+		assert!(super::Adx::new(0).is_err());
+	}
+
+	#[test]
+	fn adxr() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [f64; 7] = [
+			20.273088, 22.240577, 24.989949, 22.708386, 22.267053, 20.228463,
+			20.964146,
+		];
+
+		let mut adxr = super::Adxr::new(5).unwrap();
+		assert_eq!(adxr.lookback(), 13);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = adxr.next(*bar).unwrap();
+			assert_eq!(result, adxr.current());
+			match i.checked_sub(13) {
+				Some(j) => {
+					assert!(adxr.is_ready());
+					assert_eq!(half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!adxr.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		adxr.reset();
+		assert!(!adxr.is_ready());
+		assert_eq!(adxr.current(), None);
+
+		let series = super::adxr_series(&bars, 5).unwrap();
+		assert_eq!(series[..13], [None; 13]);
+		for (value, exp) in series[13..].iter().zip(results.iter()) {
+			assert_eq!(half_up(value.unwrap(), 6), *exp);
+		}
+		assert_eq!(super::Adxr::default().lookback(), 40);
+		match super::adxr_series(&bars[..13], 5) {
+			Err(err) => assert_eq!(err.to_string(),
+				AnalysisError::InsufficientData { needed: 14, got: 13 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+	}
+
+	#[test]
+	fn parabolic_sar() {
+		use super::Direction::{Long, Short};
+
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, super::Direction, bool); 19] = [
+			(125.360000, Long, false),
+			(127.620000, Short, true),
+			(127.620000, Short, false),
+			(124.930000, Long, true),
+			(124.994800, Long, false),
+			(125.132208, Long, false),
+			(128.430000, Short, true),
+			(128.358000, Short, false),
+			(128.287440, Short, false),
+			(128.218291, Short, false),
+			(128.071960, Short, false),
+			(127.931481, Short, false),
+			(127.796622, Short, false),
+			(127.720000, Short, false),
+			(124.560000, Long, true),
+			(124.633200, Long, false),
+			(124.778672, Long, false),
+			(124.918325, Long, false),
+			(125.052392, Long, false),
+		];
+
+		let mut sar = super::ParabolicSar::default();
+		assert_eq!(sar.lookback(), 1);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = sar.next(*bar).unwrap();
+			assert_eq!(result, sar.current());
+			match i.checked_sub(1) {
+				Some(j) => {
+					let (stop, direction, reversal) = result.unwrap();
+					assert!(sar.is_ready());
+					assert_eq!((half_up(stop, 6), direction, reversal), results[j]);
+				},
+				None => {
+					assert!(!sar.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		sar.reset();
+		assert!(!sar.is_ready());
+		assert_eq!(sar.current(), None);
+
+		let series = super::parabolic_sar_series(&bars, 0.02, 0.02, 0.2).unwrap();
+		assert_eq!(series[0], None);
+		for (value, exp) in series[1..].iter().zip(results.iter()) {
+			let (stop, direction, reversal) = value.unwrap();
+			assert_eq!((half_up(stop, 6), direction, reversal), *exp);
+		}
+
+		let tests: [([f64; 3], AnalysisError); 7] = [
+			([0.02, f64::NAN, 0.2], AnalysisError::NonFiniteInput { index: 1 }),
+			([0., 0.02, 0.2], AnalysisError::InvalidAlpha(0.)),
+			([0.02, -0.02, 0.2], AnalysisError::InvalidAlpha(-0.02)),
+			([0.02, 0.02, 0.], AnalysisError::InvalidAlpha(0.)),
+			([0.02, 1.5, 0.2], AnalysisError::InvalidAlpha(1.5)),
+			([0.02, 0.02, 2.], AnalysisError::InvalidAlpha(2.)),
+			([0.3, 0.02, 0.2], AnalysisError::InvalidAlpha(0.3)),
+		];
+
+		for test in &tests {
+			match super::ParabolicSar::new(test.0[0], test.0[1], test.0[2]) {
+				Err(err) => assert_eq!(err.to_string(), test.1.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+		assert!(super::ParabolicSar::new(0.2, 0.02, 0.2).is_ok());
+	}
+
+	#[test]
+	fn aroon() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, f64, f64); 15] = [
+			(100., 40., 60.),
+			(80., 20., 60.),
+			(60., 100., -40.),
+			(40., 80., -40.),
+			(20., 60., -40.),
+			(0., 100., -100.),
+			(0., 80., -80.),
+			(100., 60., 40.),
+			(100., 40., 60.),
+			(80., 20., 60.),
+			(100., 0., 100.),
+			(100., 0., 100.),
+			(80., 0., 80.),
+			(100., 100., 0.),
+			(80., 100., -20.),
+		];
+
+		let mut aroon = super::Aroon::new(5).unwrap();
+		assert_eq!(aroon.lookback(), 5);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = aroon.next(*bar).unwrap();
+			assert_eq!(result, aroon.current());
+			match i.checked_sub(5) {
+				Some(j) => {
+					assert!(aroon.is_ready());
+					assert_eq!(result.unwrap(), results[j]);
+				},
+				None => {
+					assert!(!aroon.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		aroon.reset();
+		assert!(!aroon.is_ready());
+		assert_eq!(aroon.current(), None);
+
+		let series = super::aroon_series(&bars, 5).unwrap();
+		assert_eq!(series[..5], [None; 5]);
+		for (value, exp) in series[5..].iter().zip(results.iter()) {
+			assert_eq!(value.unwrap(), *exp);
+		}
+		assert!(super::Aroon::new(0).is_err());
+	}
+
+	#[test]
+	fn ichimoku() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let tenkan = vec![
+			None, None, Some(126.275), Some(126.275), Some(126.55), Some(127.26),
+			Some(127.23), Some(126.63), Some(126.1), Some(125.865), Some(125.73),
+			Some(125.705), Some(125.86), Some(126.145), Some(126.395), Some(127.425),
+			Some(127.45), Some(127.49), Some(127.2), Some(127.095),
+		];
+		let kijun = vec![
+			None, None, None, None, Some(126.55), Some(126.68), Some(126.68), Some(126.63),
+			Some(126.63), Some(126.63), Some(125.965), Some(125.73), Some(125.86),
+			Some(126.14), Some(126.14), Some(126.395), Some(126.67), Some(127.45),
+			Some(127.2), Some(127.095),
+		];
+		let senkou_a = vec![
+			None, None, None, None, None, None, None, None, Some(126.55), Some(126.97),
+			Some(126.955), Some(126.63), Some(126.365), Some(126.2475), Some(125.8475),
+			Some(125.7175), Some(125.86), Some(126.1425), Some(126.2675), Some(126.91),
+			Some(127.06), Some(127.47), Some(127.2), Some(127.095),
+		];
+		let senkou_b = vec![
+			None, None, None, None, None, None, None, None, None, None, None, Some(126.63),
+			Some(126.63), Some(126.63), Some(126.495), Some(126.495), Some(126.495),
+			Some(126.14), Some(126.14), Some(126.39), Some(126.415), Some(126.415),
+			Some(126.42), Some(126.67),
+		];
+		let chikou = vec![
+			Some(126.82), Some(126.78), Some(126.39), Some(125.14), Some(126.59),
+			Some(125.87), Some(125.04), Some(124.93), Some(126.78), Some(127.42),
+			Some(126.94), Some(127.79), Some(127.02), Some(127.4), Some(126.13),
+			Some(127.62), None, None, None, None,
+		];
+		let round = |values: &[Option<f64>]| -> Vec<Option<f64>> {
+			values.iter().map(|value| value.map(|value| half_up(value, 6))).collect()
+		};
+
+		let mut ichimoku = super::Ichimoku::new(3, 5, 8, 4).unwrap();
+		assert_eq!(ichimoku.lookback(), 7);
+		assert_eq!(ichimoku.displacement(), 4);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = ichimoku.next(*bar).unwrap();
+			assert_eq!(result, ichimoku.current());
+			match i.checked_sub(7) {
+				Some(_) => {
+					let (t, k, a, b) = result.unwrap();
+					assert!(ichimoku.is_ready());
+					assert_eq!(round(&[Some(t), Some(k), Some(a), Some(b)]),
+						[tenkan[i], kijun[i], senkou_a[i + 4], senkou_b[i + 4]]);
+				},
+				None => {
+					assert!(!ichimoku.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		ichimoku.reset();
+		assert!(!ichimoku.is_ready());
+		assert_eq!(ichimoku.current(), None);
+
+		let lines = super::ichimoku_series(&bars, 3, 5, 8, 4).unwrap();
+		assert_eq!(round(&lines.tenkan), tenkan);
+		assert_eq!(round(&lines.kijun), kijun);
+		assert_eq!(round(&lines.senkou_a), senkou_a);
+		assert_eq!(round(&lines.senkou_b), senkou_b);
+		assert_eq!(lines.chikou, chikou);
+
+		match super::ichimoku_series(&bars[..7], 3, 5, 8, 4) {
+			Err(err) => assert_eq!(err.to_string(),
+				AnalysisError::InsufficientData { needed: 8, got: 7 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		assert_eq!(super::Ichimoku::default().lookback(), 51);
+		assert!(super::Ichimoku::new(3, 0, 8, 4).is_err());
+	}
+
+	#[test]
+	fn super_trend() {
+		use super::Direction::{Long, Short};
+
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, super::Direction); 15] = [
+			(125.935500, Long),
+			(125.935500, Long),
+			(126.996780, Short),
+			(126.996780, Short),
+			(126.996780, Short),
+			(126.308411, Short),
+			(126.280229, Short),
+			(124.872317, Long),
+			(126.154853, Long),
+			(126.154853, Long),
+			(126.443306, Long),
+			(126.443306, Long),
+			(126.443306, Long),
+			(128.227117, Short),
+			(128.040366, Short),
+		];
+
+		let mut super_trend = super::SuperTrend::new(5, 0.75).unwrap();
+		assert_eq!(super_trend.lookback(), 5);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = super_trend.next(*bar).unwrap();
+			assert_eq!(result, super_trend.current());
+			match i.checked_sub(5) {
+				Some(j) => {
+					let (stop, direction) = result.unwrap();
+					assert!(super_trend.is_ready());
+					assert_eq!((half_up(stop, 6), direction), results[j]);
+				},
+				None => {
+					assert!(!super_trend.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		super_trend.reset();
+		assert!(!super_trend.is_ready());
+		assert_eq!(super_trend.current(), None);
+
+		let series = super::super_trend_series(&bars, 5, 0.75).unwrap();
+		assert_eq!(series[..5], [None; 5]);
+		for (value, exp) in series[5..].iter().zip(results.iter()) {
+			let (stop, direction) = value.unwrap();
+			assert_eq!((half_up(stop, 6), direction), *exp);
+		}
+
+		let tests: [(f64, AnalysisError); 3] = [
+			(f64::INFINITY, AnalysisError::NonFiniteInput { index: 1 }),
+			(0., AnalysisError::InvalidConstant(0.)),
+			(-3., AnalysisError::InvalidConstant(-3.)),
+		];
+
+		for test in &tests {
+			match super::SuperTrend::new(5, test.0) {
+				Err(err) => assert_eq!(err.to_string(), test.1.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+		assert!(super::SuperTrend::new(0, 3.).is_err());
+	}
+
+	#[test]
+	fn chandelier_exit() {
+		use super::Direction::{Long, Short};
+
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, super::Direction); 15] = [
+			(125.391000, Long),
+			(125.596800, Long),
+			(127.573560, Short),
+			(127.552848, Short),
+			(127.347278, Short),
+			(126.966823, Short),
+			(126.830458, Short),
+			(126.830458, Short),
+			(125.449707, Long),
+			(125.585765, Long),
+			(126.086612, Long),
+			(126.095290, Long),
+			(126.143232, Long),
+			(128.473414, Short),
+			(128.340732, Short),
+		];
+
+		let mut chandelier_exit = super::ChandelierExit::new(5, 1.5).unwrap();
+		assert_eq!(chandelier_exit.lookback(), 5);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = chandelier_exit.next(*bar).unwrap();
+			assert_eq!(result, chandelier_exit.current());
+			match i.checked_sub(5) {
+				Some(j) => {
+					let (stop, direction) = result.unwrap();
+					assert!(chandelier_exit.is_ready());
+					assert_eq!((half_up(stop, 6), direction), results[j]);
+				},
+				None => {
+					assert!(!chandelier_exit.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+		let (long, short) = chandelier_exit.stops().unwrap();
+		assert_eq!((half_up(long, 6), half_up(short, 6)), (125.849268, 128.340732));
+
+		chandelier_exit.reset();
+		assert!(!chandelier_exit.is_ready());
+		assert_eq!(chandelier_exit.current(), None);
+
+		let series = super::chandelier_exit_series(&bars, 5, 1.5).unwrap();
+		assert_eq!(series[..5], [None; 5]);
+		for (value, exp) in series[5..].iter().zip(results.iter()) {
+			let (stop, direction) = value.unwrap();
+			assert_eq!((half_up(stop, 6), direction), *exp);
+		}
+
+		let tests: [(f64, AnalysisError); 3] = [
+			(f64::INFINITY, AnalysisError::NonFiniteInput { index: 1 }),
+			(0., AnalysisError::InvalidConstant(0.)),
+			(-3., AnalysisError::InvalidConstant(-3.)),
+		];
+
+		for test in &tests {
+			match super::ChandelierExit::new(5, test.0) {
+				Err(err) => assert_eq!(err.to_string(), test.1.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+		assert!(super::ChandelierExit::new(0, 3.).is_err());
+	}
+
+	#[test]
+	fn linear_regression() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		// value, slope, intercept, r squared and standard error
+		let results: [(f64, f64, f64, f64, f64); 21] = [
+			(22.286727, 0.014606, 22.155273, 0.207820, 0.091576),
+			(22.272000, 0.014000, 22.146000, 0.188264, 0.093354),
+			(22.330727, 0.022606, 22.127273, 0.368889, 0.094954),
+			(22.353091, 0.020909, 22.164909, 0.340619, 0.093422),
+			(22.456545, 0.034121, 22.149455, 0.475949, 0.114978),
+			(22.797091, 0.083576, 22.044909, 0.494771, 0.271209),
+			(23.301636, 0.153030, 21.924364, 0.574146, 0.423232),
+			(23.617818, 0.189515, 21.912182, 0.692298, 0.405737),
+			(23.918727, 0.225273, 21.891273, 0.819948, 0.338998),
+			(24.146727, 0.237939, 22.005273, 0.854910, 0.314780),
+			(24.180909, 0.215758, 22.239091, 0.772267, 0.376250),
+			(24.179636, 0.178364, 22.574364, 0.665706, 0.405892),
+			(24.152545, 0.139455, 22.897455, 0.536158, 0.416537),
+			(23.966727, 0.069939, 23.337273, 0.262769, 0.376200),
+			(23.598727, -0.024727, 23.821273, 0.080095, 0.269109),
+			(23.318000, -0.081333, 24.050000, 0.625397, 0.202143),
+			(23.268909, -0.076242, 23.955091, 0.590481, 0.203898),
+			(22.974545, -0.117879, 24.035455, 0.740909, 0.223852),
+			(22.899636, -0.118303, 23.964364, 0.743795, 0.222969),
+			(22.646727, -0.140061, 23.907273, 0.767447, 0.247591),
+			(22.334909, -0.176909, 23.927091, 0.861671, 0.227624),
+		];
+		let round = |fit: super::Regression| (
+			signed_half_up(fit.value(), 6), signed_half_up(fit.slope(), 6),
+			signed_half_up(fit.intercept(), 6), signed_half_up(fit.r_squared(), 6),
+			signed_half_up(fit.standard_error(), 6),
+		);
+
+		let mut regression = super::LinearRegression::new(10).unwrap();
+		assert_eq!(regression.lookback(), 9);
+		for (i, value) in values.iter().enumerate() {
+			let result = regression.next(*value).unwrap();
+			assert_eq!(result, regression.current());
+			match i.checked_sub(9) {
+				Some(j) => {
+					assert!(regression.is_ready());
+					assert_eq!(round(result.unwrap()), results[j]);
+				},
+				None => {
+					assert!(!regression.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		let fit = regression.current().unwrap();
+		assert!((fit.forecast() - 22.158).abs() < 1e-9);
+		let (upper, middle, lower) = fit.channel(2.);
+		assert_eq!((signed_half_up(upper, 6), signed_half_up(middle, 6), signed_half_up(lower, 6)),
+			(22.790157, 22.334909, 21.879661));
+
+		regression.reset();
+		assert!(!regression.is_ready());
+		assert_eq!(regression.current(), None);
+
+		let series = super::linear_regression_series(&values, 10).unwrap();
+		assert_eq!(series[..9], [None; 9]);
+		for (value, exp) in series[9..].iter().zip(results.iter()) {
+			assert_eq!(round(value.unwrap()), *exp);
+		}
+		for (i, exp) in results.iter().enumerate() {
+			let fit = super::linear_regression(&values[i..i + 10]).unwrap();
+			assert_eq!(round(fit), *exp);
+		}
+
+		let fit = super::linear_regression(&[5., 5., 5.]).unwrap();
+		assert_eq!((fit.slope(), fit.r_squared(), fit.standard_error()), (0., 1., 0.));
+		let tests: [(&[f64], AnalysisError); 3] = [
+			(&[], AnalysisError::SliceIsEmpty),
+			(&[1.], AnalysisError::InsufficientData { needed: 2, got: 1 }),
+			(&[1., f64::NAN], AnalysisError::NonFiniteInput { index: 1 }),
+		];
+		for test in &tests {
+			match super::linear_regression(test.0) {
+				Err(err) => assert_eq!(err.to_string(), test.1.to_string()),
+				_ => panic!("return type mismatch"),
+			}
+		}
+		assert!(super::LinearRegression::new(1).is_err());
+
+		// The fit is invariant under shifting the values, so high price levels
+		// must reproduce the same results.
+		for level in &[5e4, 1e6, 1e8] {
+			let shifted: Vec<f64> = values.iter().map(|value| value + level).collect();
+			let close = |fit: super::Regression, exp: &(f64, f64, f64, f64, f64)| {
+				assert!((fit.value() - level - exp.0).abs() < 2e-6);
+				assert!((fit.slope() - exp.1).abs() < 2e-6);
+				assert!((fit.r_squared() - exp.3).abs() < 2e-6);
+				assert!((fit.standard_error() - exp.4).abs() < 2e-6);
+			};
+			let mut regression = super::LinearRegression::new(10).unwrap();
+			for (i, value) in shifted.iter().enumerate() {
+				if let Some(fit) = regression.next(*value).unwrap() {
+					close(fit, &results[i - 9]);
+					close(super::linear_regression(&shifted[i - 9..=i]).unwrap(), &results[i - 9]);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn vortex() {
+		let data: [(f64, f64, f64, f64); 20] = [
+			(125.36, 127.01, 125.36, 3463.), (126.50, 127.62, 126.16, 2820.),
+			(125.17, 126.59, 124.93, 3104.), (126.09, 127.35, 126.09, 1815.),
+			(126.82, 128.17, 126.82, 2264.), (126.78, 128.43, 126.48, 3016.),
+			(126.39, 127.37, 126.03, 4145.), (125.14, 126.42, 124.83, 3820.),
+			(126.59, 126.90, 126.39, 2654.), (125.87, 126.85, 125.72, 2880.),
+			(125.04, 125.65, 124.56, 3156.), (124.93, 125.72, 124.57, 2541.),
+			(126.78, 127.16, 125.07, 4562.), (127.42, 127.72, 126.86, 3302.),
+			(126.94, 127.69, 126.63, 2884.), (127.79, 128.22, 126.80, 3412.),
+			(127.02, 128.27, 126.71, 2740.), (127.40, 128.09, 126.80, 2981.),
+			(126.13, 128.27, 126.13, 3690.), (127.62, 127.74, 125.92, 2544.),
+		];
+		let bars = to_bars(&data);
+		let results: [(f64, f64); 15] = [
+			(0.868707, 0.617966),
+			(0.806732, 0.847991),
+			(0.808534, 0.838074),
+			(0.807339, 0.824541),
+			(0.697555, 1.009009),
+			(0.544180, 1.183731),
+			(0.597983, 1.025937),
+			(0.837731, 0.689974),
+			(1.025148, 0.813609),
+			(1.091181, 0.808670),
+			(1.297059, 0.589706),
+			(1.266297, 0.615811),
+			(1.263158, 0.838915),
+			(0.902276, 0.926372),
+			(0.913730, 0.993925),
+		];
+
+		let mut vortex = super::Vortex::new(5).unwrap();
+		assert_eq!(vortex.lookback(), 5);
+		for (i, bar) in bars.iter().enumerate() {
+			let result = vortex.next(*bar).unwrap();
+			assert_eq!(result, vortex.current());
+			match i.checked_sub(5) {
+				Some(j) => {
+					let (plus, minus) = result.unwrap();
+					assert!(vortex.is_ready());
+					assert_eq!((half_up(plus, 6), half_up(minus, 6)), results[j]);
+				},
+				None => {
+					assert!(!vortex.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+		}
+
+		vortex.reset();
+		assert!(!vortex.is_ready());
+		assert_eq!(vortex.current(), None);
+
+		let series = super::vortex_series(&bars, 5).unwrap();
+		assert_eq!(series[..5], [None; 5]);
+		for (value, exp) in series[5..].iter().zip(results.iter()) {
+			let (plus, minus) = value.unwrap();
+			assert_eq!((half_up(plus, 6), half_up(minus, 6)), *exp);
+		}
+		assert!(super::Vortex::new(0).is_err());
+	}
+
+	#[test]
+	fn trix() {
+		let values: [f64; 30] = [
+			22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24,
+			22.29, 22.15, 22.39, 22.38, 22.61, 23.36, 24.05, 23.75, 23.83,
+			23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33, 22.68,
+			23.10, 22.40, 22.17,
+		];
+		let results: [f64; 23] = [
+			0.159839, 0.114265, 0.082453, -0.020684, 0.056264, 0.104535,
+			0.240942, 0.709106, 1.273492, 1.229634, 1.019605, 0.830234,
+			0.463138, 0.301985, 0.235447, 0.068482, -0.278047, -0.487792,
+			-0.394495, -0.606354, -0.453053, -0.649897, -0.839377,
+		];
+		let signals: [f64; 20] = [
+			0.083968, 0.072887, 0.085546, 0.147704, 0.372265, 0.732756,
+			0.931507, 0.966747, 0.912142, 0.732540, 0.560318, 0.430370,
+			0.285615, 0.060150, -0.159027, -0.253214, -0.394470, -0.417903,
+			-0.510701, -0.642171,
+		];
+
+		let mut trix = super::Trix::new(3, Some(4)).unwrap();
+		assert_eq!(trix.lookback(), 7);
+		for (i, value) in values.iter().enumerate() {
+			let result = trix.next(*value).unwrap();
+			assert_eq!(result, trix.current());
+			match i.checked_sub(7) {
+				Some(j) => {
+					assert!(trix.is_ready());
+					assert_eq!(signed_half_up(result.unwrap(), 6), results[j]);
+				},
+				None => {
+					assert!(!trix.is_ready());
+					assert_eq!(result, None);
+				},
+			}
+			match i.checked_sub(10) {
+				Some(j) => assert_eq!(signed_half_up(trix.signal().unwrap(), 6), signals[j]),
+				None => assert_eq!(trix.signal(), None),
+			}
+		}
+
+		trix.reset();
+		assert!(!trix.is_ready());
+		assert_eq!(trix.current(), None);
+		assert_eq!(trix.signal(), None);
+
+		let series = super::trix_series(&values, 3).unwrap();
+		assert_eq!(series[..7], [None; 7]);
+		for (value, exp) in series[7..].iter().zip(results.iter()) {
+			assert_eq!(signed_half_up(value.unwrap(), 6), *exp);
+		}
+		let series = super::trix_signal_series(&values, 3, 4).unwrap();
+		assert_eq!(series[..10], [None; 10]);
+		for (value, exp) in series[10..].iter().zip(results[3..].iter().zip(signals.iter())) {
+			let (trix, signal) = value.unwrap();
+			assert_eq!((signed_half_up(trix, 6), signed_half_up(signal, 6)), (*exp.0, *exp.1));
+		}
+
+		let series = super::trix_series(&[0., 0., 0., 1.], 1).unwrap();
+		assert_eq!(series, [None, Some(0.), Some(0.), Some(0.)]);
+
+		assert!(super::Trix::new(3, Some(0)).is_err());
+		match super::trix_signal_series(&values[..10], 3, 4) {
+			Err(err) => assert_eq!(err.to_string(),
+				AnalysisError::InsufficientData { needed: 11, got: 10 }.to_string()),
+			_ => panic!("return type mismatch"),
+		}
+		match super::trix_signal_series(&[1., 2., f64::NAN], 1, 1) {
+			Err(err) => assert_eq!(err.to_string(),
+				AnalysisError::NonFiniteInput { index: 2 }.to_string()),
+			_ => panic!("return type mismatch"),
 		}
 	}
 }
@@ -0,0 +1,265 @@
+//! Window contains rolling window primitives shared by the indicators.
+use std::collections::VecDeque;
+
+/// Rolling maximum or minimum of the last `period` values.
+///
+/// Values that can no longer become the extremum are dropped as new values
+/// arrive, which keeps the deque monotonic and makes each update O(1)
+/// amortised.
+#[derive(Debug, Clone)]
+pub struct Extremum {
+	period: usize,
+	maximum: bool,
+	count: usize,
+	deque: VecDeque<(usize, f64)>,
+}
+
+impl Extremum {
+	/// Creates a new rolling maximum over `period` values.
+	pub fn max(period: usize) -> Extremum {
+		Extremum::new(period, true)
+	}
+
+	/// Creates a new rolling minimum over `period` values.
+	pub fn min(period: usize) -> Extremum {
+		Extremum::new(period, false)
+	}
+
+	fn new(period: usize, maximum: bool) -> Extremum {
+		assert!(period > 0, "period must be greater than 0");
+		Extremum {
+			period,
+			maximum,
+			count: 0,
+			deque: VecDeque::with_capacity(period),
+		}
+	}
+
+	/// Adds a value and returns the extremum of the window, or `None` if
+	/// fewer than `period` values have been seen.
+	pub fn next(&mut self, value: f64) -> Option<f64> {
+		while let Some(&(_, last)) = self.deque.back() {
+			match self.maximum {
+				true if last <= value => self.deque.pop_back(),
+				false if last >= value => self.deque.pop_back(),
+				_ => break,
+			};
+		}
+		self.deque.push_back((self.count, value));
+		self.count += 1;
+		if let Some(&(index, _)) = self.deque.front() {
+			if index + self.period < self.count {
+				self.deque.pop_front();
+			}
+		}
+		match self.count < self.period {
+			true => None,
+			false => self.deque.front().map(|&(_, value)| value),
+		}
+	}
+
+	/// Returns the number of values added after the extremum, or `None` if
+	/// fewer than `period` values have been seen. The latest extremum is used
+	/// when there are several equal ones.
+	pub fn age(&self) -> Option<usize> {
+		match self.count < self.period {
+			true => None,
+			false => self.deque.front().map(|&(index, _)| self.count - 1 - index),
+		}
+	}
+
+	/// Clears the window.
+	pub fn reset(&mut self) {
+		self.count = 0;
+		self.deque.clear();
+	}
+
+	/// Returns the number of values consumed before the first extremum.
+	pub fn lookback(&self) -> usize {
+		self.period - 1
+	}
+}
+
+/// Fixed-capacity ring buffer of the last `capacity` values.
+#[derive(Debug, Clone)]
+pub struct Ring<T> {
+	values: Vec<T>,
+	capacity: usize,
+	index: usize,
+}
+
+impl<T: Copy> Ring<T> {
+	/// Creates a new ring buffer holding `capacity` values.
+	pub fn new(capacity: usize) -> Ring<T> {
+		assert!(capacity > 0, "capacity must be greater than 0");
+		Ring {
+			values: Vec::with_capacity(capacity),
+			capacity,
+			index: 0,
+		}
+	}
+
+	/// Adds a value and returns the value it replaced once the buffer is full.
+	pub fn push(&mut self, value: T) -> Option<T> {
+		let old = match self.is_full() {
+			true => Some(self.values[self.index]),
+			false => None,
+		};
+		match old {
+			Some(_) => self.values[self.index] = value,
+			None => self.values.push(value),
+		}
+		self.index = (self.index + 1) % self.capacity;
+		old
+	}
+
+	/// Returns the value added `age` values ago, where 0 is the latest value.
+	pub fn get(&self, age: usize) -> Option<T> {
+		match age < self.values.len() {
+			true => Some(self.values[(self.index + self.capacity - 1 - age) % self.capacity]),
+			false => None,
+		}
+	}
+
+	/// Returns the number of values in the buffer.
+	pub fn len(&self) -> usize {
+		self.values.len()
+	}
+
+	/// Returns the capacity of the buffer.
+	pub fn capacity(&self) -> usize {
+		self.capacity
+	}
+
+	/// Returns `true` once the buffer holds `capacity` values.
+	pub fn is_full(&self) -> bool {
+		self.values.len() == self.capacity
+	}
+
+	/// Removes all values.
+	pub fn clear(&mut self) {
+		self.values.clear();
+		self.index = 0;
+	}
+}
+
+/// Rolling mean and mean absolute deviation of the last `period` values.
+///
+/// The mean comes from a running sum, but the deviation has to be measured
+/// from the current mean, so each update visits the whole window and costs
+/// O(period).
+#[derive(Debug, Clone)]
+pub struct MeanDeviation {
+	window: Ring<f64>,
+	sum: Sum,
+}
+
+impl MeanDeviation {
+	/// Creates a new rolling mean absolute deviation over `period` values.
+	pub fn new(period: usize) -> MeanDeviation {
+		MeanDeviation {
+			window: Ring::new(period),
+			sum: Sum::default(),
+		}
+	}
+
+	/// Adds a value and returns the mean and the mean absolute deviation of
+	/// the window, or `None` if fewer than `period` values have been seen.
+	pub fn next(&mut self, value: f64) -> Option<(f64, f64)> {
+		if let Some(old) = self.window.push(value) {
+			self.sum.add(-old);
+		}
+		self.sum.add(value);
+		if !self.window.is_full() {
+			return None;
+		}
+		let period = self.window.capacity() as f64;
+		let mean = self.sum.value() / period;
+		let deviation = self.window.values.iter()
+			.fold(0., |sum, value| sum + (value - mean).abs());
+		Some((mean, deviation / period))
+	}
+
+	/// Clears the window.
+	pub fn reset(&mut self) {
+		self.window.clear();
+		self.sum.reset();
+	}
+
+	/// Returns the number of values consumed before the first output.
+	pub fn lookback(&self) -> usize {
+		self.window.capacity() - 1
+	}
+}
+
+/// Percent rank of the latest value among the previous `period` values.
+///
+/// The rank is the percentage of the previous values that are strictly less
+/// than the latest one. The previous values are not kept in order, so each
+/// update visits the whole window and costs O(period).
+#[derive(Debug, Clone)]
+pub struct PercentRank {
+	window: Ring<f64>,
+}
+
+impl PercentRank {
+	/// Creates a new percent rank over the previous `period` values.
+	pub fn new(period: usize) -> PercentRank {
+		PercentRank {
+			window: Ring::new(period),
+		}
+	}
+
+	/// Adds a value and returns its rank between 0 and 100, or `None` if
+	/// fewer than `period` values preceded it.
+	pub fn next(&mut self, value: f64) -> Option<f64> {
+		let rank = match self.window.is_full() {
+			true => {
+				let below = self.window.values.iter().filter(|&&old| old < value).count();
+				Some(100. * below as f64 / self.window.capacity() as f64)
+			},
+			false => None,
+		};
+		self.window.push(value);
+		rank
+	}
+
+	/// Clears the window.
+	pub fn reset(&mut self) {
+		self.window.clear();
+	}
+
+	/// Returns the number of values consumed before the first rank.
+	pub fn lookback(&self) -> usize {
+		self.window.capacity()
+	}
+}
+
+/// Running sum using Kahan summation to keep rounding errors from
+/// accumulating over long series.
+#[derive(Debug, Clone, Default)]
+pub struct Sum {
+	sum: f64,
+	compensation: f64,
+}
+
+impl Sum {
+	/// Adds a value to the sum.
+	pub fn add(&mut self, value: f64) {
+		let y = value - self.compensation;
+		let t = self.sum + y;
+		self.compensation = (t - self.sum) - y;
+		self.sum = t;
+	}
+
+	/// Returns the sum.
+	pub fn value(&self) -> f64 {
+		self.sum
+	}
+
+	/// Resets the sum to 0.
+	pub fn reset(&mut self) {
+		self.sum = 0.;
+		self.compensation = 0.;
+	}
+}